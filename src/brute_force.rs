@@ -0,0 +1,130 @@
+//! exhaustive reference used to cross-check the enumerations on small inputs.
+use crate::graph::{set_to_vec, EdgeSet, VertexSet};
+use crate::maximal_biclique::Concept;
+use crate::maximal_common_induced_subgraph::Mapping;
+use crate::maximal_induced_interval_subgraph::IntervalModel;
+use crate::maximal_induced_split_subgraph::SplitPartition;
+use crate::ProsimitySearchable;
+use std::collections::HashSet;
+
+/// all maximal subsets of {0, ..., universe - 1} that meet `is_feasible`.
+///
+/// the property is not assumed to be hereditary, so a feasible set is maximal iff
+/// no strict superset of it is feasible.
+pub fn maximal_sets(
+    universe: usize,
+    is_feasible: impl Fn(&HashSet<usize>) -> bool,
+) -> HashSet<Vec<usize>> {
+    assert!(universe <= 16, "brute force is only for small universes");
+    let feasible = (0..1usize << universe)
+        .filter(|&mask| is_feasible(&to_set(universe, mask)))
+        .collect::<Vec<_>>();
+    feasible
+        .iter()
+        .filter(|&&mask| {
+            !feasible
+                .iter()
+                .any(|&other| other != mask && other & mask == mask)
+        })
+        .map(|&mask| set_to_vec(&to_set(universe, mask)))
+        .collect()
+}
+
+/// assert that `problem` enumerates the maximal subsets of {0, ..., universe - 1} that meet
+/// `is_feasible`, each once: no two solutions have the same elements.
+pub fn assert_enumerates<P>(
+    problem: &P,
+    universe: usize,
+    is_feasible: impl Fn(&HashSet<usize>) -> bool,
+) where
+    P: ProsimitySearchable,
+    P::Solutions: Elements,
+{
+    let solutions = problem
+        .enemurate()
+        .iter()
+        .map(Elements::elements)
+        .collect::<Vec<_>>();
+    let distinct = solutions.iter().cloned().collect::<HashSet<_>>();
+    assert_eq!(solutions.len(), distinct.len());
+    assert_eq!(distinct, maximal_sets(universe, is_feasible));
+}
+
+/// a solution as the sorted elements of the universe it consists of
+pub trait Elements {
+    fn elements(&self) -> Vec<usize>;
+}
+
+impl Elements for VertexSet {
+    fn elements(&self) -> Vec<usize> {
+        self.0.clone()
+    }
+}
+
+impl Elements for EdgeSet {
+    fn elements(&self) -> Vec<usize> {
+        self.indices.clone()
+    }
+}
+
+impl Elements for Mapping {
+    fn elements(&self) -> Vec<usize> {
+        self.indices.clone()
+    }
+}
+
+impl Elements for Concept {
+    fn elements(&self) -> Vec<usize> {
+        set_to_vec(&self.to_set())
+    }
+}
+
+impl Elements for SplitPartition {
+    fn elements(&self) -> Vec<usize> {
+        set_to_vec(&self.to_set())
+    }
+}
+
+impl Elements for IntervalModel {
+    fn elements(&self) -> Vec<usize> {
+        self.vertices.clone()
+    }
+}
+
+/// the graphs the enumerations are cross-checked on: four seeds for each edge density,
+/// in percent. the sparse graphs are mostly disconnected, and the dense ones have large
+/// cliques.
+pub fn random_graphs(vertices: usize) -> impl Iterator<Item = HashSet<(usize, usize)>> {
+    [20, 50, 80]
+        .into_iter()
+        .flat_map(move |density| (0..4).map(move |seed| random_graph(vertices, density, seed)))
+}
+
+/// small pseudo-random graph of density 1/2, deterministic in `seed`
+pub fn random_edges(vertices: usize, seed: u64) -> HashSet<(usize, usize)> {
+    random_graph(vertices, 50, seed)
+}
+
+/// small pseudo-random graph, each edge being present with probability `density` percent,
+/// deterministic in `seed`
+pub fn random_graph(vertices: usize, density: u64, seed: u64) -> HashSet<(usize, usize)> {
+    let mut state = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    let mut edges = HashSet::new();
+    for u in 0..vertices {
+        for v in u + 1..vertices {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            if (state >> 33) % 100 < density {
+                edges.insert((u, v));
+            }
+        }
+    }
+    edges
+}
+
+fn to_set(universe: usize, mask: usize) -> HashSet<usize> {
+    (0..universe).filter(|i| mask >> i & 1 == 1).collect()
+}
@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+use crate::graph::VertexSet;
+use crate::hereditary::{HereditaryProblem, Property};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// maximal vertex sets inducing a connected subgraph with a hereditary property P.
+///
+/// connected-P is not hereditary, but every prefix of `canonical_order(S)` is a component,
+/// and proximity(S, S*) is the longest prefix of `canonical_order(S*)` contained in S.
+pub struct Connected<P: Property> {
+    hereditary: HereditaryProblem<P>,
+}
+
+impl<P: Property> Connected<P> {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>, property: P) -> Self {
+        Self::new(HereditaryProblem::init(vertices, edges, property))
+    }
+
+    pub fn new(hereditary: HereditaryProblem<P>) -> Self {
+        Self { hereditary }
+    }
+
+    /// greedily add vertices while the set stays connected and feasible,
+    /// restarting from the smallest vertex since a skipped vertex may become adjacent later
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        let mut n = 0;
+        while n < self.hereditary.graph.vertices {
+            if component.contains(&n) {
+                n += 1;
+                continue;
+            }
+            component.insert(n);
+            if self.is_connected_feasible(&component) {
+                n = 0;
+            } else {
+                component.remove(&n);
+                n += 1;
+            }
+        }
+        component
+    }
+
+    pub(crate) fn is_connected_feasible(&self, set: &HashSet<usize>) -> bool {
+        self.hereditary.graph.is_connected(set) && self.hereditary.is_feasible(set)
+    }
+}
+
+impl<P: Property> ProsimitySearchable for Connected<P> {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        (0..self.hereditary.graph.vertices)
+            .filter(|v| !solution.0.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> VertexSet {
+        self.comp(HashSet::new()).into()
+    }
+}
+
+impl<P: Property> CanonicalReconstruction for Connected<P> {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn canonical_order(&self, solution: &VertexSet) -> Vec<usize> {
+        self.hereditary.graph.canonical_order(&solution.to_set())
+    }
+
+    /// COMP(CC(S ∪ {v} \ X, v)) for each removable set X of the hereditary problem
+    fn neightbors(&self, solution: &VertexSet, vertex: usize) -> Vec<VertexSet> {
+        let solution = solution.to_set();
+        self.hereditary
+            .removable_sets(&solution, vertex)
+            .into_iter()
+            .map(|x| {
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(vertex);
+                self.comp(self.hereditary.graph.cc(&component, vertex))
+                    .into()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::graph::Graph;
+    use crate::maximal_connected_induced_tree::MaximalConnectedInducedTree;
+    use crate::maximal_induced_forest::Forest;
+
+    #[test]
+    fn for_connected_max_degree_2() {
+        // connected induced subgraphs of maximum degree at most 2, i.e., paths and cycles
+        let max_degree_2 = |graph: &Graph, set: &VertexSet| {
+            set.0
+                .iter()
+                .all(|&u| set.0.iter().filter(|&&v| graph.is_adjacent(u, v)).count() <= 2)
+        };
+        for edges in random_graphs(9) {
+            let problem = Connected::init(9, edges, max_degree_2);
+            assert_enumerates(&problem, 9, |set| problem.is_connected_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_trees() {
+        for edges in random_graphs(9) {
+            // shrinking to a minimal obstruction against the cycles closed by v
+            let problem = Connected::init(9, edges.clone(), |graph: &Graph, set: &VertexSet| {
+                Forest::is_forest(graph, &set.to_set())
+            });
+            assert_eq!(
+                problem.enemurate(),
+                MaximalConnectedInducedTree::init(9, edges, Forest).enemurate()
+            );
+        }
+    }
+}
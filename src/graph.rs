@@ -0,0 +1,265 @@
+#![allow(dead_code)]
+use std::collections::HashSet;
+
+/// undirected graph on V(G) = {0, ..., vertices - 1}.
+///
+/// an edge may be stored as either (u, v) or (v, u).
+pub struct Graph {
+    pub vertices: usize,
+    pub edges: HashSet<(usize, usize)>,
+}
+
+impl Graph {
+    pub fn new(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        Self { vertices, edges }
+    }
+
+    /// whether {u, v} is in E(G)
+    pub fn is_adjacent(&self, u: usize, v: usize) -> bool {
+        self.edges.contains(&(u, v)) || self.edges.contains(&(v, u))
+    }
+
+    /// N(v)
+    pub fn neighborhood(&self, v: usize) -> HashSet<usize> {
+        (0..self.vertices)
+            .filter(|&u| u != v && self.is_adjacent(u, v))
+            .collect()
+    }
+
+    /// E(G) as (u, v) with u < v, sorted. an index into it identifies an edge.
+    pub fn edge_list(&self) -> Vec<(usize, usize)> {
+        let mut edges = self
+            .edges
+            .iter()
+            .filter(|&&(u, v)| u != v)
+            .map(|&(u, v)| (u.min(v), u.max(v)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        edges.sort();
+        edges
+    }
+
+    /// the connected component of G[set] containing v
+    pub fn cc(&self, set: &HashSet<usize>, v: usize) -> HashSet<usize> {
+        if !set.contains(&v) {
+            return HashSet::new();
+        }
+        let mut new = HashSet::new();
+        new.insert(v);
+        let mut queue = std::collections::VecDeque::new();
+        queue.push_back(v);
+        while let Some(v) = queue.pop_front() {
+            for &u in set {
+                if !new.contains(&u) && self.is_adjacent(u, v) {
+                    new.insert(u);
+                    queue.push_back(u);
+                }
+            }
+        }
+        new
+    }
+
+    /// whether G[set] is connected. the empty set is connected.
+    pub fn is_connected(&self, set: &HashSet<usize>) -> bool {
+        match set.iter().next() {
+            Some(&v) => self.cc(set, v).len() == set.len(),
+            None => true,
+        }
+    }
+
+    /// ordering of `set` starting from its smallest vertex, each next vertex being the
+    /// smallest one adjacent to the prefix, or the smallest remaining one if there is none.
+    /// any prefix of the ordering of a connected set is connected.
+    pub fn canonical_order(&self, set: &HashSet<usize>) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::new();
+        let mut rest = set_to_vec(set);
+        while !rest.is_empty() {
+            let i = rest
+                .iter()
+                .position(|&u| order.iter().any(|&w| self.is_adjacent(u, w)))
+                .unwrap_or(0);
+            order.push(rest.remove(i));
+        }
+        order
+    }
+}
+
+/// directed graph on V(G) = {0, ..., vertices - 1}. (u, v) is the arc from u to v.
+pub struct DiGraph {
+    pub vertices: usize,
+    pub arcs: HashSet<(usize, usize)>,
+}
+
+impl DiGraph {
+    pub fn new(vertices: usize, arcs: HashSet<(usize, usize)>) -> Self {
+        Self { vertices, arcs }
+    }
+
+    pub fn has_arc(&self, u: usize, v: usize) -> bool {
+        self.arcs.contains(&(u, v))
+    }
+}
+
+/// a subset of V(G), kept sorted so that it can be hashed as a solution.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VertexSet(pub Vec<usize>);
+
+impl VertexSet {
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.0.iter().copied().collect()
+    }
+
+    /// S ∩ S*, the proximity of hereditary problems
+    pub fn intersection(&self, other: &VertexSet) -> HashSet<usize> {
+        intersection(&self.to_set(), &other.to_set())
+    }
+}
+
+impl From<HashSet<usize>> for VertexSet {
+    fn from(set: HashSet<usize>) -> Self {
+        Self(set_to_vec(&set))
+    }
+}
+
+impl std::fmt::Display for VertexSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", print_vec(&self.0))
+    }
+}
+
+/// a subset of E(G), given both by indices into the universe E(G) and by the edges
+/// themselves, so that it can be printed without the universe.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EdgeSet {
+    pub indices: Vec<usize>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl EdgeSet {
+    pub fn new(set: &HashSet<usize>, universe: &[(usize, usize)]) -> Self {
+        let indices = set_to_vec(set);
+        let edges = indices.iter().map(|&i| universe[i]).collect();
+        Self { indices, edges }
+    }
+
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.indices.iter().copied().collect()
+    }
+}
+
+impl std::fmt::Display for EdgeSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let edges = self
+            .edges
+            .iter()
+            .map(|&(u, v)| (u + 1, v + 1))
+            .collect::<Vec<_>>();
+        write!(f, "{edges:?}")
+    }
+}
+
+/// vertices are printed 1-indexed
+pub fn print_vec(vec: &[usize]) -> Vec<usize> {
+    vec.iter().copied().map(|e| e + 1).collect()
+}
+
+pub fn set_to_vec(set: &HashSet<usize>) -> Vec<usize> {
+    let mut vec = set.iter().copied().collect::<Vec<_>>();
+    vec.sort();
+    vec
+}
+
+pub fn intersection(set: &HashSet<usize>, set_: &HashSet<usize>) -> HashSet<usize> {
+    set.intersection(set_).copied().collect()
+}
+
+/// a minimal infeasible subset of `set` ∪ {v} for a hereditary `is_feasible`, if
+/// `set` ∪ {v} is infeasible, found by dropping vertices while the rest stays infeasible.
+/// it contains v, as `set` is feasible.
+pub fn minimal_obstruction(
+    set: &HashSet<usize>,
+    v: usize,
+    is_feasible: impl Fn(&HashSet<usize>) -> bool,
+) -> Option<Vec<usize>> {
+    let mut obstruction = set.clone();
+    obstruction.insert(v);
+    if is_feasible(&obstruction) {
+        return None;
+    }
+    for u in set_to_vec(set) {
+        obstruction.remove(&u);
+        if is_feasible(&obstruction) {
+            obstruction.insert(u);
+        }
+    }
+    Some(set_to_vec(&obstruction))
+}
+
+/// all minimal X ⊆ `solution` such that `solution` ∪ {v} \ X has no obstruction through v.
+///
+/// `obstruction(rest, v)` gives the elements of an obstruction in `rest` ∪ {v} through v,
+/// if any. every such X hits it, so X is found by branching on its elements other than v.
+/// the i-th branch keeps the elements before the i-th, so the branches are disjoint and
+/// each X is reached once rather than in every order, and X is minimal iff putting back
+/// any of its elements creates an obstruction. the number of branches is still
+/// exponential in the obstruction size in the worst case.
+pub fn removable_sets(
+    solution: &HashSet<usize>,
+    v: usize,
+    obstruction: impl Fn(&HashSet<usize>, usize) -> Option<Vec<usize>>,
+) -> Vec<HashSet<usize>> {
+    let mut found = Vec::new();
+    let mut kept = HashSet::new();
+    branch(
+        solution,
+        v,
+        HashSet::new(),
+        &mut kept,
+        &obstruction,
+        &mut found,
+    );
+    found
+}
+
+fn branch(
+    solution: &HashSet<usize>,
+    v: usize,
+    removed: HashSet<usize>,
+    kept: &mut HashSet<usize>,
+    obstruction: &impl Fn(&HashSet<usize>, usize) -> Option<Vec<usize>>,
+    found: &mut Vec<HashSet<usize>>,
+) {
+    let mut rest = solution
+        .difference(&removed)
+        .copied()
+        .collect::<HashSet<_>>();
+    match obstruction(&rest, v) {
+        Some(obstruction_) => {
+            let choices = obstruction_
+                .into_iter()
+                .filter(|&u| u != v && !kept.contains(&u))
+                .collect::<Vec<_>>();
+            for &u in &choices {
+                let mut removed = removed.clone();
+                removed.insert(u);
+                branch(solution, v, removed, kept, obstruction, found);
+                kept.insert(u);
+            }
+            for u in choices {
+                kept.remove(&u);
+            }
+        }
+        None => {
+            let minimal = removed.iter().all(|&u| {
+                rest.insert(u);
+                let needed = obstruction(&rest, v).is_some();
+                rest.remove(&u);
+                needed
+            });
+            if minimal {
+                found.push(removed);
+            }
+        }
+    }
+}
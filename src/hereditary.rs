@@ -0,0 +1,213 @@
+#![allow(dead_code)]
+use crate::graph::{intersection, minimal_obstruction, removable_sets, Graph, VertexSet};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+/// a hereditary property of vertex sets: if `set` has it, so does every subset.
+pub trait Property {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool;
+
+    /// whether `set` ∪ {v} is feasible, given that `set` is.
+    /// override when it can be checked faster than from scratch.
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        let mut extended = set.to_set();
+        extended.insert(v);
+        self.is_feasible(graph, &extended.into())
+    }
+
+    /// the vertices of an infeasible subset of `set` ∪ {v} through v, if `set` ∪ {v} is
+    /// infeasible, on which the removable sets branch. by default a minimal one, found by
+    /// shrinking; override when obstructions can be found directly.
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        minimal_obstruction(&set.to_set(), v, |set| {
+            self.is_feasible(graph, &set.clone().into())
+        })
+    }
+}
+
+/// a predicate is a property, so that one can be prototyped as a closure
+impl<F: Fn(&Graph, &VertexSet) -> bool> Property for F {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        self(graph, set)
+    }
+}
+
+/// what a solution of `HereditaryProblem<P, C>` reports: the vertex set itself by default,
+/// or a certificate of P computed from it, e.g., a split partition or an interval model.
+pub trait Certificate<P>: Eq + std::hash::Hash + std::fmt::Display + Clone {
+    /// the certificate of a feasible `set`
+    fn certify(graph: &Graph, property: &P, set: &HashSet<usize>) -> Self;
+
+    fn to_set(&self) -> HashSet<usize>;
+}
+
+impl<P> Certificate<P> for VertexSet {
+    fn certify(_: &Graph, _: &P, set: &HashSet<usize>) -> Self {
+        set.clone().into()
+    }
+
+    fn to_set(&self) -> HashSet<usize> {
+        VertexSet::to_set(self)
+    }
+}
+
+/// maximal vertex sets with a hereditary property P, with `start`, `comp` and the
+/// neighbor rule derived from P alone. solutions are reported as C.
+///
+/// removable sets are the minimal X ⊆ S such that S ∪ {v} \ X has P. every infeasible
+/// subset of S ∪ {v} contains v and must be hit by X, so they are found by branching on
+/// the vertices of `Property::obstruction`. shrinking to a minimal one costs O(|S|)
+/// feasibility checks, and the branching is exponential in the obstruction size, which is
+/// fine for small forbidden structures.
+pub struct HereditaryProblem<P: Property, C = VertexSet> {
+    pub(crate) graph: Graph,
+    pub(crate) property: P,
+    certificate: PhantomData<C>,
+}
+
+impl<P: Property> HereditaryProblem<P> {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>, property: P) -> Self {
+        Self::with_certificates(vertices, edges, property)
+    }
+}
+
+impl<P: Property, C> HereditaryProblem<P, C> {
+    /// solutions reported as certificates C rather than as vertex sets
+    pub fn with_certificates(vertices: usize, edges: HashSet<(usize, usize)>, property: P) -> Self {
+        Self {
+            graph: Graph::new(vertices, edges),
+            property,
+            certificate: PhantomData,
+        }
+    }
+
+    pub(crate) fn is_feasible(&self, set: &HashSet<usize>) -> bool {
+        self.property.is_feasible(&self.graph, &set.clone().into())
+    }
+
+    /// greedily add vertices in increasing order while the set stays feasible
+    fn comp(&self, component: HashSet<usize>) -> HashSet<usize> {
+        let mut component = VertexSet::from(component);
+        for n in 0..self.graph.vertices {
+            if !component.0.contains(&n) && self.property.can_add(&self.graph, &component, n) {
+                let position = component.0.partition_point(|&u| u < n);
+                component.0.insert(position, n);
+            }
+        }
+        component.to_set()
+    }
+
+    /// all minimal X ⊆ S such that S ∪ {v} \ X is feasible,
+    /// found by branching on a vertex of each obstruction through v.
+    pub(crate) fn removable_sets(
+        &self,
+        solution: &HashSet<usize>,
+        v: usize,
+    ) -> Vec<HashSet<usize>> {
+        removable_sets(solution, v, |rest, v| {
+            self.property
+                .obstruction(&self.graph, &rest.clone().into(), v)
+        })
+    }
+}
+
+impl<P: Property, C: Certificate<P>> ProsimitySearchable for HereditaryProblem<P, C> {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = C;
+
+    fn proximity(solution: &C, solution_: &C) -> HashSet<usize> {
+        intersection(&solution.to_set(), &solution_.to_set())
+    }
+
+    fn neighbors(&self, solution: &C) -> Vec<C> {
+        let set = solution.to_set();
+        (0..self.graph.vertices)
+            .filter(|v| !set.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> C {
+        C::certify(&self.graph, &self.property, &self.comp(HashSet::new()))
+    }
+}
+
+impl<P: Property, C: Certificate<P>> CanonicalReconstruction for HereditaryProblem<P, C> {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = C;
+
+    /// removable sets are the minimal vertex sets of S hitting every obstruction through v
+    fn neightbors(&self, solution: &C, vertex: usize) -> Vec<C> {
+        let solution = solution.to_set();
+        self.removable_sets(&solution, vertex)
+            .into_iter()
+            .map(|x| {
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(vertex);
+                C::certify(&self.graph, &self.property, &self.comp(component))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::maximal_clique::MaximalCliques;
+    use crate::maximal_induced_forest::MaximalInducedForest;
+
+    /// cliques, with the incremental check
+    struct Clique;
+
+    impl Property for Clique {
+        fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+            set.0
+                .iter()
+                .all(|&u| set.0.iter().all(|&v| u == v || graph.is_adjacent(u, v)))
+        }
+
+        fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+            set.0.iter().all(|&u| graph.is_adjacent(u, v))
+        }
+    }
+
+    #[test]
+    fn for_closure() {
+        // induced subgraphs of maximum degree at most 2
+        let max_degree_2 = |graph: &Graph, set: &VertexSet| {
+            set.0
+                .iter()
+                .all(|&u| set.0.iter().filter(|&&v| graph.is_adjacent(u, v)).count() <= 2)
+        };
+        for edges in random_graphs(9) {
+            let problem = HereditaryProblem::init(9, edges, max_degree_2);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_cliques() {
+        for edges in random_graphs(9) {
+            let problem = HereditaryProblem::init(9, edges.clone(), Clique);
+            assert_eq!(
+                problem.enemurate(),
+                MaximalCliques::init(9, edges).bron_kerbosch()
+            );
+        }
+    }
+
+    #[test]
+    fn for_forests() {
+        for edges in random_graphs(9) {
+            let forest = MaximalInducedForest::init(9, edges.clone());
+            let problem = HereditaryProblem::init(9, edges, |_: &Graph, set: &VertexSet| {
+                forest.is_forest(&set.to_set())
+            });
+            assert_eq!(problem.enemurate(), forest.enemurate());
+        }
+    }
+}
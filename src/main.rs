@@ -16,6 +16,11 @@ pub trait ProsimitySearchable {
     type Solutions: Eq + std::hash::Hash + std::fmt::Display + Clone;
     /// not actually used in algorithm, but needed to prove the correctness.
     /// proximity: Self::Solutions x Self::Solutions -> 2^Universe
+    ///
+    /// for a hereditary property, i.e., one closed under taking subsets, S ∩ S* works:
+    /// every subset of S* is a component, so S ∩ S* can grow towards S* one element
+    /// at a time, and |S ∩ S*| is maximized only by S = S*. such problems use
+    /// `VertexSet::intersection`, or `graph::intersection` for other solution types.
     fn proximity(
         _solution: &Self::Solutions,
         _solution_: &Self::Solutions,
@@ -25,6 +30,10 @@ pub trait ProsimitySearchable {
     /// 1. computable in time polynomial in |U|.
     /// 2. For all S, S* in Self::Solutions, there exists S' in neighbors(S) s.t. |proximity(S', S*)| > |proximity(S, S*)|
     /// 3. For any fixed S*, |proximity(S, S*)| is maximized for (and only for) S = S*
+    ///
+    /// problems whose removable sets branch on obstructions of unbounded size, e.g.,
+    /// cycles or holes, meet 2 and 3 but not 1, and say so: they enumerate correctly but
+    /// without polynomial delay.
     fn neighbors(&self, solution: &Self::Solutions) -> Vec<Self::Solutions>;
     /// one solution of the problem is to be idintified in time polynomial in |U|.
     fn start(&self) -> Self::Solutions;
@@ -36,6 +45,8 @@ pub trait ProsimitySearchable {
         solutions
     }
 
+    // `is_multiple_of` is too recent for the toolchains this crate supports
+    #[allow(clippy::manual_is_multiple_of)]
     fn enume(
         &self,
         solution: Self::Solutions,
@@ -68,6 +79,10 @@ pub trait CanonicalReconstruction {
     /// not actually used in algorithm, but needed to prove the correctness.
     ///
     /// ordering s1, ..., s|S| of S's elemtns that any prefix of this corresponds to Self::Components
+    ///
+    /// when proximity(S, S*) is the longest prefix of `canonical_order(S*)` contained in S,
+    /// it depends on the instance, which the static `proximity` cannot see, so such problems
+    /// leave `proximity` to the default and implement this instead.
     fn canonical_order(&self, _solution: &Self::Solutions) -> Vec<usize> {
         vec![]
     }
@@ -80,8 +95,37 @@ pub trait CanonicalReconstruction {
     fn neightbors(&self, solution: &Self::Solutions, vertex: usize) -> Vec<Self::Solutions>;
 }
 
+#[cfg(test)]
+mod brute_force;
+mod connected;
+mod graph;
+mod hereditary;
+mod maximal_balanced_induced_subgraph;
+mod maximal_biclique;
+mod maximal_bipartite_edge_set;
+mod maximal_clique;
+mod maximal_common_induced_subgraph;
+mod maximal_connected_common_induced_subgraph;
 mod maximal_connected_induced_bipartile_subgraph;
+mod maximal_connected_induced_tree;
+mod maximal_connected_induced_triangle_free_subgraph;
+mod maximal_independent_set;
+mod maximal_induced_biclique;
 mod maximal_induced_bipartite_subgraph;
+mod maximal_induced_bounded_degree_subgraph;
+mod maximal_induced_chordal_subgraph;
+mod maximal_induced_cluster_subgraph;
+mod maximal_induced_cograph;
+mod maximal_induced_dag;
+mod maximal_induced_forest;
+mod maximal_induced_h_free_subgraph;
+mod maximal_induced_interval_subgraph;
+mod maximal_induced_k_colorable_subgraph;
+mod maximal_induced_matching;
+mod maximal_induced_split_subgraph;
+mod maximal_induced_triangle_free_subgraph;
+mod maximal_k_plex;
+mod maximal_s_club;
 mod poly_maximal_connected_induced_bipartile_subgraph;
 
 use maximal_connected_induced_bipartile_subgraph::MaximalConnectedInducedBipartiteSubgraph;
@@ -0,0 +1,146 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use crate::maximal_connected_induced_bipartile_subgraph::union_find_library::UnionFind;
+use std::collections::HashSet;
+
+/// the property of inducing a balanced subgraph of a signed graph, each edge being
+/// labeled + or −, i.e., one whose every cycle has an even number of negative edges.
+pub struct Balanced {
+    positive: Graph,
+    negative: Graph,
+}
+
+impl Balanced {
+    pub fn new(
+        vertices: usize,
+        positive: HashSet<(usize, usize)>,
+        negative: HashSet<(usize, usize)>,
+    ) -> Self {
+        Self {
+            positive: Graph::new(vertices, positive),
+            negative: Graph::new(vertices, negative),
+        }
+    }
+
+    /// parity union-find on 2|V|: u and u + |V| stand for the two sides of u
+    fn parity(&self, set: &HashSet<usize>) -> UnionFind {
+        let n = self.positive.vertices;
+        let mut tree = UnionFind::new(n * 2);
+        for &u in set {
+            for &v in set {
+                if self.positive.is_adjacent(u, v) {
+                    tree.unite(u, v);
+                    tree.unite(u + n, v + n);
+                }
+                if self.negative.is_adjacent(u, v) {
+                    tree.unite(u, v + n);
+                    tree.unite(u + n, v);
+                }
+            }
+        }
+        tree
+    }
+
+    fn is_balanced(&self, set: &HashSet<usize>) -> bool {
+        let n = self.positive.vertices;
+        let mut tree = self.parity(set);
+        set.iter().all(|&i| !tree.same(i, i + n))
+    }
+
+    /// (B_0, B_1) of a balanced set: positive edges stay inside a side and
+    /// negative edges go across
+    pub fn bipartition(&self, solution: &HashSet<usize>) -> (HashSet<usize>, HashSet<usize>) {
+        let n = self.positive.vertices;
+        let mut tree = self.parity(solution);
+        solution
+            .iter()
+            .partition(|&&v| tree.find(v) < tree.find(v + n))
+    }
+}
+
+impl Property for Balanced {
+    fn is_feasible(&self, _: &Graph, set: &VertexSet) -> bool {
+        self.is_balanced(&set.to_set())
+    }
+}
+
+/// maximal vertex sets inducing a balanced subgraph of a signed graph.
+/// if every edge is negative, balanced is the same as bipartite.
+///
+/// each component of G[S] has two switchings, so v joining a side of a fixed bipartition
+/// of S misses the solutions that switch components or drop a vertex of a path between
+/// two neighbors of v. the removable sets are therefore the minimal X such that
+/// S ∪ {v} \ X is balanced, as for every hereditary property. the obstructions are the
+/// unbalanced cycles through v, which may be long, so `neighbors` is exponential in the
+/// worst case.
+pub type MaximalBalancedInducedSubgraph = HereditaryProblem<Balanced>;
+
+impl MaximalBalancedInducedSubgraph {
+    /// the underlying graph has the edges of both signs
+    pub fn from_signed_edges(
+        vertices: usize,
+        positive: HashSet<(usize, usize)>,
+        negative: HashSet<(usize, usize)>,
+    ) -> Self {
+        let edges = positive.union(&negative).copied().collect();
+        Self::init(vertices, edges, Balanced::new(vertices, positive, negative))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+    use crate::ProsimitySearchable;
+
+    #[test]
+    fn for_maximal_balanced_induced_subgraph() {
+        for (seed, edges) in random_graphs(9).enumerate() {
+            let signs = random_edges(9, seed as u64 + 100);
+            let (positive, negative) = edges.into_iter().partition(|e| signs.contains(e));
+            let problem = MaximalBalancedInducedSubgraph::from_signed_edges(9, positive, negative);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_proximity() {
+        // from every solution S, some neighbor gets closer to every other solution S*
+        for seed in 0..4 {
+            let edges = random_edges(8, seed);
+            let signs = random_edges(8, seed + 100);
+            let (positive, negative) = edges.into_iter().partition(|e| signs.contains(e));
+            let problem = MaximalBalancedInducedSubgraph::from_signed_edges(8, positive, negative);
+            let proximity = |s: &VertexSet, s_: &VertexSet| {
+                MaximalBalancedInducedSubgraph::proximity(s, s_).len()
+            };
+            let solutions = problem.enemurate();
+            for s in &solutions {
+                let neighbors = problem.neighbors(s);
+                for s_ in solutions.iter().filter(|&s_| s_ != s) {
+                    let closer = |n| proximity(n, s_) > proximity(s, s_);
+                    assert!(neighbors.iter().any(closer));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn for_all_negative() {
+        // odd cycle 0 - 1 - 2 - 3 - 4 - 0 with a chord (0, 2)
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (0, 2)]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let problem = MaximalBalancedInducedSubgraph::from_signed_edges(5, HashSet::new(), edges);
+        let solutions = problem
+            .enemurate()
+            .into_iter()
+            .map(|s| s.0)
+            .collect::<HashSet<_>>();
+        let expected = [vec![1, 2, 3, 4], vec![0, 1, 3, 4], vec![0, 2, 3, 4]]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(solutions, expected);
+    }
+}
@@ -0,0 +1,218 @@
+#![allow(dead_code)]
+use crate::graph::{intersection, print_vec, set_to_vec};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// biclique A × B of a bipartite graph, i.e., a formal concept (extent A, intent B)
+/// when the graph is the incidence relation of objects and attributes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Concept {
+    pub objects: Vec<usize>,
+    pub attributes: Vec<usize>,
+}
+
+impl Concept {
+    /// the universe L ∪ R is indexed by 2i for the object i and 2j + 1 for the attribute j
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.objects
+            .iter()
+            .map(|i| 2 * i)
+            .chain(self.attributes.iter().map(|j| 2 * j + 1))
+            .collect()
+    }
+
+    fn from_set(set: &HashSet<usize>) -> Self {
+        let vec = set_to_vec(set);
+        Self {
+            objects: vec.iter().filter(|e| *e % 2 == 0).map(|e| e / 2).collect(),
+            attributes: vec.iter().filter(|e| *e % 2 == 1).map(|e| e / 2).collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for Concept {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} x {:?}",
+            print_vec(&self.objects),
+            print_vec(&self.attributes)
+        )
+    }
+}
+
+/// maximal bicliques A × B ⊆ E of a bipartite graph with sides L and R, not necessarily
+/// induced. they are exactly the formal concepts of the relation E ⊆ L × R.
+pub struct MaximalBicliques {
+    left: usize,
+    right: usize,
+    edges: HashSet<(usize, usize)>,
+}
+
+impl MaximalBicliques {
+    /// `edges` contains (l, r) for l in L = {0, ..., left - 1} and r in R = {0, ..., right - 1}
+    pub fn init(left: usize, right: usize, edges: HashSet<(usize, usize)>) -> Self {
+        Self { left, right, edges }
+    }
+
+    /// object-attribute matrix: `matrix[i][j]` tells whether the object i has the attribute j
+    pub fn from_matrix(matrix: &[Vec<bool>]) -> Self {
+        let right = matrix.first().map_or(0, |row| row.len());
+        assert!(
+            matrix.iter().all(|row| row.len() == right),
+            "rows must have the same length"
+        );
+        let edges = matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|e| *e.1)
+                    .map(move |(j, _)| (i, j))
+            })
+            .collect();
+        Self::init(matrix.len(), right, edges)
+    }
+
+    /// L ∪ R, objects first
+    fn elements(&self) -> impl Iterator<Item = usize> {
+        (0..self.left)
+            .map(|i| 2 * i)
+            .chain((0..self.right).map(|j| 2 * j + 1))
+    }
+
+    /// greedily add objects, then attributes, while A × B stays in E
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in self.elements() {
+            if !component.contains(&n) && component.iter().all(|&e| !self.conflict(e, n)) {
+                component.insert(n);
+            }
+        }
+        component
+    }
+
+    /// an object and an attribute conflict iff they are not related
+    fn conflict(&self, e: usize, f: usize) -> bool {
+        match (e % 2, f % 2) {
+            (0, 1) => !self.edges.contains(&(e / 2, f / 2)),
+            (1, 0) => !self.edges.contains(&(f / 2, e / 2)),
+            _ => false,
+        }
+    }
+
+    fn is_biclique(&self, set: &HashSet<usize>) -> bool {
+        set.iter()
+            .all(|&e| set.iter().all(|&f| !self.conflict(e, f)))
+    }
+}
+
+impl std::str::FromStr for MaximalBicliques {
+    type Err = String;
+
+    /// one row of `0` and `1` per object, e.g., "110\n011"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let matrix = s
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|c| match c {
+                        '0' => Ok(false),
+                        '1' => Ok(true),
+                        _ => Err(format!("unexpected character {c:?} in {line:?}")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(row) = matrix.iter().find(|row| row.len() != matrix[0].len()) {
+            return Err(format!(
+                "row of length {} in a matrix of width {}",
+                row.len(),
+                matrix[0].len()
+            ));
+        }
+        Ok(Self::from_matrix(&matrix))
+    }
+}
+
+impl ProsimitySearchable for MaximalBicliques {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = Concept;
+
+    fn proximity(solution: &Concept, solution_: &Concept) -> HashSet<usize> {
+        intersection(&solution.to_set(), &solution_.to_set())
+    }
+
+    fn neighbors(&self, solution: &Concept) -> Vec<Concept> {
+        let set = solution.to_set();
+        self.elements()
+            .filter(|e| !set.contains(e))
+            .flat_map(|e| self.neightbors(solution, e))
+            .collect()
+    }
+
+    fn start(&self) -> Concept {
+        Concept::from_set(&self.comp(HashSet::new()))
+    }
+}
+
+impl CanonicalReconstruction for MaximalBicliques {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = Concept;
+
+    /// the only removable set is the elements of the other side unrelated to e
+    fn neightbors(&self, solution: &Concept, element: usize) -> Vec<Concept> {
+        let mut component = solution
+            .to_set()
+            .into_iter()
+            .filter(|&f| !self.conflict(element, f))
+            .collect::<HashSet<_>>();
+        component.insert(element);
+        vec![Concept::from_set(&self.comp(component))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_bicliques() {
+        for edges in random_graphs(10) {
+            // pairs (u, v) with u < 5 <= v of a random graph, as a 5 x 5 relation
+            let edges = edges
+                .into_iter()
+                .filter(|&(u, v)| u < 5 && v >= 5)
+                .map(|(u, v)| (u, v - 5))
+                .collect();
+            let problem = MaximalBicliques::init(5, 5, edges);
+            assert_enumerates(&problem, 10, |set| problem.is_biclique(set));
+        }
+    }
+
+    #[test]
+    fn for_formal_concepts() {
+        let problem = "110\n011\n".parse::<MaximalBicliques>().unwrap();
+        let concepts = problem.enemurate();
+        let expected = [
+            (vec![], vec![0, 1, 2]),
+            (vec![0], vec![0, 1]),
+            (vec![1], vec![1, 2]),
+            (vec![0, 1], vec![1]),
+        ]
+        .into_iter()
+        .map(|(objects, attributes)| Concept {
+            objects,
+            attributes,
+        })
+        .collect::<HashSet<_>>();
+        assert_eq!(concepts, expected);
+        assert!("10\n2".parse::<MaximalBicliques>().is_err());
+        assert!("10\n1".parse::<MaximalBicliques>().is_err());
+    }
+}
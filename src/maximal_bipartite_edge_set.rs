@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+use crate::graph::{intersection, removable_sets, EdgeSet, Graph};
+use crate::maximal_connected_induced_bipartile_subgraph::union_find_library::UnionFind;
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::{HashSet, VecDeque};
+
+/// maximal edge sets F ⊆ E(G) such that (V(G), F) is bipartite.
+///
+/// the cut of a 2-colouring of (V, F) contains F and is bipartite, so every solution is
+/// exactly the cut of its 2-colouring: the solutions are the inclusion-maximal cuts of G,
+/// and the maximum cuts are among them.
+///
+/// the removable sets branch on odd cycles, which may be long, so `neighbors` is
+/// exponential in the worst case.
+pub struct MaximalBipartiteEdgeSets {
+    graph: Graph,
+    universe: Vec<(usize, usize)>,
+}
+
+impl MaximalBipartiteEdgeSets {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        let graph = Graph::new(vertices, edges);
+        let universe = graph.edge_list();
+        Self { graph, universe }
+    }
+
+    /// greedily add edges in increasing order while (V, F) stays bipartite
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.universe.len() {
+            if component.contains(&n) {
+                continue;
+            }
+            component.insert(n);
+            if !self.is_bipartite(&component) {
+                component.remove(&n);
+            }
+        }
+        component
+    }
+
+    /// doubled union-find: u and u + |V| stand for the two sides of u
+    fn parity(&self, set: &HashSet<usize>) -> UnionFind {
+        let n = self.graph.vertices;
+        let mut tree = UnionFind::new(n * 2);
+        for &e in set {
+            let (u, v) = self.universe[e];
+            tree.unite(u, v + n);
+            tree.unite(u + n, v);
+        }
+        tree
+    }
+
+    fn is_bipartite(&self, set: &HashSet<usize>) -> bool {
+        let n = self.graph.vertices;
+        let mut tree = self.parity(set);
+        (0..n).all(|i| !tree.same(i, i + n))
+    }
+
+    /// the two sides of the cut defined by a solution
+    pub fn bipartition(&self, solution: &EdgeSet) -> (HashSet<usize>, HashSet<usize>) {
+        let n = self.graph.vertices;
+        let mut tree = self.parity(&solution.to_set());
+        (0..n).partition(|&v| tree.find(v) < tree.find(v + n))
+    }
+
+    /// edges of a path in (V, set) between the endpoints of e, if any.
+    /// in a bipartite set, such a path closes an odd cycle with e iff it has even length,
+    /// and then every such path does.
+    fn closed_cycle(&self, set: &HashSet<usize>, e: usize) -> Option<Vec<usize>> {
+        let (u, v) = self.universe[e];
+        let mut parent = vec![None; self.graph.vertices];
+        let mut queue = VecDeque::new();
+        parent[u] = Some(e);
+        queue.push_back(u);
+        while let Some(w) = queue.pop_front() {
+            if w == v {
+                let mut path = Vec::new();
+                let mut w = v;
+                while w != u {
+                    let f = parent[w].unwrap();
+                    path.push(f);
+                    let (a, b) = self.universe[f];
+                    w = if a == w { b } else { a };
+                }
+                return (path.len() % 2 == 0).then_some(path);
+            }
+            for &f in set {
+                let (a, b) = self.universe[f];
+                let x = if a == w {
+                    b
+                } else if b == w {
+                    a
+                } else {
+                    continue;
+                };
+                if parent[x].is_none() {
+                    parent[x] = Some(f);
+                    queue.push_back(x);
+                }
+            }
+        }
+        None
+    }
+
+    /// all minimal X ⊆ F such that F ∪ {e} \ X is bipartite, i.e., the minimal cuts
+    /// between the endpoints of e when they are on the same side.
+    fn removable_sets(&self, solution: &HashSet<usize>, e: usize) -> Vec<HashSet<usize>> {
+        removable_sets(solution, e, |rest, e| self.closed_cycle(rest, e))
+    }
+}
+
+impl ProsimitySearchable for MaximalBipartiteEdgeSets {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = EdgeSet;
+
+    fn proximity(solution: &EdgeSet, solution_: &EdgeSet) -> HashSet<usize> {
+        intersection(&solution.to_set(), &solution_.to_set())
+    }
+
+    fn neighbors(&self, solution: &EdgeSet) -> Vec<EdgeSet> {
+        (0..self.universe.len())
+            .filter(|e| !solution.indices.contains(e))
+            .flat_map(|e| self.neightbors(solution, e))
+            .collect()
+    }
+
+    fn start(&self) -> EdgeSet {
+        EdgeSet::new(&self.comp(HashSet::new()), &self.universe)
+    }
+}
+
+impl CanonicalReconstruction for MaximalBipartiteEdgeSets {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = EdgeSet;
+
+    /// removable sets are the minimal edge sets of F breaking every odd cycle closed by e
+    fn neightbors(&self, solution: &EdgeSet, edge: usize) -> Vec<EdgeSet> {
+        let solution = solution.to_set();
+        self.removable_sets(&solution, edge)
+            .into_iter()
+            .map(|x| {
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(edge);
+                EdgeSet::new(&self.comp(component), &self.universe)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_bipartite_edge_sets() {
+        for edges in random_graphs(6) {
+            let problem = MaximalBipartiteEdgeSets::init(6, edges);
+            assert_enumerates(&problem, problem.universe.len(), |set| {
+                problem.is_bipartite(set)
+            });
+        }
+    }
+}
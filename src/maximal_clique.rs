@@ -0,0 +1,138 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// maximal cliques of G.
+pub struct MaximalCliques {
+    pub(crate) graph: Graph,
+}
+
+impl MaximalCliques {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        Self {
+            graph: Graph::new(vertices, edges),
+        }
+    }
+
+    /// reference enumeration by Bron–Kerbosch with pivoting.
+    pub fn bron_kerbosch(&self) -> HashSet<VertexSet> {
+        let mut cliques = HashSet::new();
+        self.expand(
+            HashSet::new(),
+            (0..self.graph.vertices).collect(),
+            HashSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    fn expand(
+        &self,
+        r: HashSet<usize>,
+        mut p: HashSet<usize>,
+        mut x: HashSet<usize>,
+        cliques: &mut HashSet<VertexSet>,
+    ) {
+        if p.is_empty() && x.is_empty() {
+            cliques.insert(r.into());
+            return;
+        }
+        // pivot u maximizing |P ∩ N(u)|
+        let u = *p
+            .union(&x)
+            .max_by_key(|&&u| p.iter().filter(|&&w| self.graph.is_adjacent(u, w)).count())
+            .unwrap();
+        let candidates = p
+            .iter()
+            .copied()
+            .filter(|&v| !self.graph.is_adjacent(u, v))
+            .collect::<Vec<_>>();
+        for v in candidates {
+            let n_v = self.graph.neighborhood(v);
+            let mut r_ = r.clone();
+            r_.insert(v);
+            self.expand(
+                r_,
+                p.intersection(&n_v).copied().collect(),
+                x.intersection(&n_v).copied().collect(),
+                cliques,
+            );
+            p.remove(&v);
+            x.insert(v);
+        }
+    }
+
+    /// greedily add vertices in increasing order while the set stays a clique
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.graph.vertices {
+            if !component.contains(&n) && self.can_add(&component, n) {
+                component.insert(n);
+            }
+        }
+        component
+    }
+
+    fn can_add(&self, set: &HashSet<usize>, v: usize) -> bool {
+        set.iter().all(|&u| self.graph.is_adjacent(u, v))
+    }
+
+    fn is_clique(&self, set: &HashSet<usize>) -> bool {
+        set.iter()
+            .all(|&u| set.iter().all(|&v| u == v || self.graph.is_adjacent(u, v)))
+    }
+}
+
+impl ProsimitySearchable for MaximalCliques {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn proximity(solution: &VertexSet, solution_: &VertexSet) -> HashSet<usize> {
+        solution.intersection(solution_)
+    }
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        (0..self.graph.vertices)
+            .filter(|v| !solution.0.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> VertexSet {
+        self.comp(HashSet::new()).into()
+    }
+}
+
+impl CanonicalReconstruction for MaximalCliques {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    /// the only removable set is S \ N(v)
+    fn neightbors(&self, solution: &VertexSet, vertex: usize) -> Vec<VertexSet> {
+        let n_v = self.graph.neighborhood(vertex);
+        let mut component = solution
+            .to_set()
+            .intersection(&n_v)
+            .copied()
+            .collect::<HashSet<_>>();
+        component.insert(vertex);
+        vec![self.comp(component).into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_cliques() {
+        for edges in random_graphs(9) {
+            let problem = MaximalCliques::init(9, edges);
+            assert_eq!(problem.enemurate(), problem.bron_kerbosch());
+            assert_enumerates(&problem, 9, |set| problem.is_clique(set));
+        }
+    }
+}
@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+use crate::graph::{intersection, Graph, VertexSet};
+use crate::maximal_clique::MaximalCliques;
+use crate::ProsimitySearchable;
+use std::collections::HashSet;
+
+/// a partial injection G → H, given both by vertices of the modular product and by the
+/// pairs (g, h) they stand for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    pub indices: Vec<usize>,
+    pub pairs: Vec<(usize, usize)>,
+}
+
+impl Mapping {
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.indices.iter().copied().collect()
+    }
+}
+
+impl std::fmt::Display for Mapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pairs = self
+            .pairs
+            .iter()
+            .map(|&(g, h)| format!("{} -> {}", g + 1, h + 1))
+            .collect::<Vec<_>>();
+        write!(f, "[{}]", pairs.join(", "))
+    }
+}
+
+/// maximal common induced subgraphs of G and H, as maximal cliques of their modular product.
+///
+/// the modular product has a vertex (g, h) for each g in V(G) and h in V(H), and
+/// (g, h), (g', h') are adjacent iff g ≠ g', h ≠ h' and {g, g'} ∈ E(G) ⇔ {h, h'} ∈ E(H).
+/// its cliques are exactly the isomorphisms between induced subgraphs of G and H.
+/// proximity(S, S*) = S ∩ S* over the vertices of the product.
+pub struct MaximalCommonInducedSubgraphs {
+    pub(crate) g: Graph,
+    pub(crate) h: Graph,
+    pub(crate) product: MaximalCliques,
+}
+
+impl MaximalCommonInducedSubgraphs {
+    pub fn init(
+        g_vertices: usize,
+        g_edges: HashSet<(usize, usize)>,
+        h_vertices: usize,
+        h_edges: HashSet<(usize, usize)>,
+    ) -> Self {
+        let g = Graph::new(g_vertices, g_edges);
+        let h = Graph::new(h_vertices, h_edges);
+        let vertices = g.vertices * h.vertices;
+        let mut edges = HashSet::new();
+        for x in 0..vertices {
+            for y in x + 1..vertices {
+                let (g_x, h_x) = (x / h.vertices, x % h.vertices);
+                let (g_y, h_y) = (y / h.vertices, y % h.vertices);
+                if g_x != g_y && h_x != h_y && g.is_adjacent(g_x, g_y) == h.is_adjacent(h_x, h_y) {
+                    edges.insert((x, y));
+                }
+            }
+        }
+        Self {
+            g,
+            h,
+            product: MaximalCliques::init(vertices, edges),
+        }
+    }
+
+    /// the vertex of the modular product standing for (g, h)
+    pub(crate) fn index(&self, g: usize, h: usize) -> usize {
+        g * self.h.vertices + h
+    }
+
+    pub(crate) fn to_mapping(&self, clique: &VertexSet) -> Mapping {
+        Mapping {
+            indices: clique.0.clone(),
+            pairs: clique
+                .0
+                .iter()
+                .map(|&x| (x / self.h.vertices, x % self.h.vertices))
+                .collect(),
+        }
+    }
+}
+
+impl ProsimitySearchable for MaximalCommonInducedSubgraphs {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = Mapping;
+
+    fn proximity(solution: &Mapping, solution_: &Mapping) -> HashSet<usize> {
+        intersection(&solution.to_set(), &solution_.to_set())
+    }
+
+    fn neighbors(&self, solution: &Mapping) -> Vec<Mapping> {
+        self.product
+            .neighbors(&VertexSet(solution.indices.clone()))
+            .iter()
+            .map(|clique| self.to_mapping(clique))
+            .collect()
+    }
+
+    fn start(&self) -> Mapping {
+        self.to_mapping(&self.product.start())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+
+    #[test]
+    fn for_maximal_common_induced_subgraphs() {
+        for (seed, g) in random_graphs(3).enumerate() {
+            let h = random_edges(4, seed as u64 + 100);
+            let problem = MaximalCommonInducedSubgraphs::init(3, g, 4, h);
+            let is_isomorphism = |set: &HashSet<usize>| {
+                let pairs = set.iter().map(|&x| (x / 4, x % 4)).collect::<Vec<_>>();
+                pairs.iter().all(|&(g, h)| {
+                    pairs.iter().all(|&(g_, h_)| {
+                        (g == g_) == (h == h_)
+                            && problem.g.is_adjacent(g, g_) == problem.h.is_adjacent(h, h_)
+                    })
+                })
+            };
+            assert_enumerates(&problem, 12, is_isomorphism);
+        }
+    }
+
+    #[test]
+    fn for_path_in_triangle() {
+        // the path 0 - 1 - 2 and the triangle share exactly the edges as induced subgraphs
+        let path = vec![(0, 1), (1, 2)].into_iter().collect();
+        let triangle = vec![(0, 1), (1, 2), (2, 0)].into_iter().collect();
+        let problem = MaximalCommonInducedSubgraphs::init(3, path, 3, triangle);
+        let solutions = problem.enemurate();
+        assert!(solutions.iter().all(|s| s.pairs.len() == 2));
+        assert!(solutions.iter().all(|s| s.pairs[0].0 + 1 == s.pairs[1].0));
+        assert_eq!(solutions.len(), 2 * 6);
+    }
+}
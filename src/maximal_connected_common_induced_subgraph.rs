@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::maximal_common_induced_subgraph::{Mapping, MaximalCommonInducedSubgraphs};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// maximal common induced subgraphs of G and H whose matched vertices are connected,
+/// as maximal c-cliques of the modular product.
+///
+/// an edge of the modular product between (g, h) and (g', h') is a c-edge if
+/// {g, g'} ∈ E(G), and so {h, h'} ∈ E(H). a c-clique is a clique connected by c-edges,
+/// which is the same as a mapping connected in both G and H.
+/// proximity(S, S*) is the longest prefix of `canonical_order(S*)` contained in S.
+pub struct MaximalConnectedCommonInducedSubgraphs {
+    common: MaximalCommonInducedSubgraphs,
+    c_edges: Graph,
+}
+
+impl MaximalConnectedCommonInducedSubgraphs {
+    pub fn init(
+        g_vertices: usize,
+        g_edges: HashSet<(usize, usize)>,
+        h_vertices: usize,
+        h_edges: HashSet<(usize, usize)>,
+    ) -> Self {
+        let common = MaximalCommonInducedSubgraphs::init(g_vertices, g_edges, h_vertices, h_edges);
+        let c_edges = common
+            .product
+            .graph
+            .edges
+            .iter()
+            .copied()
+            .filter(|&(x, y)| common.g.is_adjacent(x / h_vertices, y / h_vertices))
+            .collect();
+        Self {
+            c_edges: Graph::new(g_vertices * h_vertices, c_edges),
+            common,
+        }
+    }
+
+    /// greedily add vertices of the product while the set stays a c-clique,
+    /// restarting as in `Connected::comp`
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        let product = &self.common.product.graph;
+        let mut n = 0;
+        while n < product.vertices {
+            if !component.contains(&n)
+                && component.iter().all(|&u| product.is_adjacent(u, n))
+                && (component.is_empty()
+                    || component.iter().any(|&u| self.c_edges.is_adjacent(u, n)))
+            {
+                component.insert(n);
+                n = 0;
+            } else {
+                n += 1;
+            }
+        }
+        component
+    }
+}
+
+impl ProsimitySearchable for MaximalConnectedCommonInducedSubgraphs {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = Mapping;
+
+    fn neighbors(&self, solution: &Mapping) -> Vec<Mapping> {
+        (0..self.c_edges.vertices)
+            .filter(|x| !solution.indices.contains(x))
+            .flat_map(|x| self.neightbors(solution, x))
+            .collect()
+    }
+
+    fn start(&self) -> Mapping {
+        self.common.to_mapping(&self.comp(HashSet::new()).into())
+    }
+}
+
+impl CanonicalReconstruction for MaximalConnectedCommonInducedSubgraphs {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = Mapping;
+
+    fn canonical_order(&self, solution: &Mapping) -> Vec<usize> {
+        self.c_edges.canonical_order(&solution.to_set())
+    }
+
+    /// COMP(CC(S ∩ N(x) ∪ {x}, x)), where CC follows the c-edges
+    fn neightbors(&self, solution: &Mapping, vertex: usize) -> Vec<Mapping> {
+        let n_x = self.common.product.graph.neighborhood(vertex);
+        let mut component = solution
+            .to_set()
+            .intersection(&n_x)
+            .copied()
+            .collect::<HashSet<_>>();
+        component.insert(vertex);
+        let clique = self.comp(self.c_edges.cc(&component, vertex));
+        vec![self.common.to_mapping(&VertexSet::from(clique))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+
+    #[test]
+    fn for_maximal_connected_common_induced_subgraphs() {
+        for (seed, g) in random_graphs(3).enumerate() {
+            let h = random_edges(4, seed as u64 + 100);
+            let problem = MaximalConnectedCommonInducedSubgraphs::init(3, g.clone(), 4, h);
+            let g = Graph::new(3, g);
+            let is_connected_isomorphism = |set: &HashSet<usize>| {
+                let pairs = set.iter().map(|&x| (x / 4, x % 4)).collect::<Vec<_>>();
+                pairs.iter().all(|&(a, b)| {
+                    pairs.iter().all(|&(a_, b_)| {
+                        (a == a_) == (b == b_)
+                            && problem.common.g.is_adjacent(a, a_)
+                                == problem.common.h.is_adjacent(b, b_)
+                    })
+                }) && g.is_connected(&pairs.iter().map(|p| p.0).collect())
+            };
+            assert_enumerates(&problem, 12, is_connected_isomorphism);
+        }
+    }
+}
@@ -1,11 +1,7 @@
 #![allow(dead_code)]
+use crate::graph::Graph;
 use std::collections::{HashMap, HashSet};
 
-struct Graph {
-    vertices: usize,
-    edges: HashSet<(usize, usize)>,
-}
-
 pub struct MaximalConnectedInducedBipartiteSubgraph {
     graph: Graph,
     pub solutions: HashSet<Vec<usize>>,
@@ -31,6 +27,8 @@ impl MaximalConnectedInducedBipartiteSubgraph {
         self.enume(first_solution, 1);
     }
 
+    // `deps` is only read by the commented-out alternative output below
+    #[allow(clippy::only_used_in_recursion)]
     fn enume(&mut self, solution: HashSet<usize>, deps: usize) {
         let solution_vec = set_to_vec(&solution);
         let u = *self.index.get(&solution_vec).unwrap();
@@ -102,7 +100,7 @@ impl MaximalConnectedInducedBipartiteSubgraph {
     }
 
     fn bipartition(&self, solution: &HashSet<usize>) -> (HashSet<usize>, HashSet<usize>) {
-        let solution_vec = set_to_vec(&solution);
+        let solution_vec = set_to_vec(solution);
         let mut visited = solution_vec.iter().map(|_| None).collect::<Vec<_>>();
 
         let mut b_0 = HashSet::new();
@@ -146,7 +144,7 @@ impl MaximalConnectedInducedBipartiteSubgraph {
             return HashSet::new();
         }
         let mut new = HashSet::new();
-        let set_vec = set_to_vec(&set);
+        let set_vec = set_to_vec(set);
         let mut visited = set_vec.iter().map(|_| false).collect::<Vec<_>>();
 
         let i = set_vec.iter().position(|&e| e == v).unwrap();
@@ -208,7 +206,7 @@ impl MaximalConnectedInducedBipartiteSubgraph {
         if set.is_empty() {
             return true;
         }
-        let set_vec = set_to_vec(&set);
+        let set_vec = set_to_vec(set);
         let mut visited = set_vec.iter().map(|_| false).collect::<Vec<_>>();
 
         visited[0] = true;
@@ -229,7 +227,7 @@ impl MaximalConnectedInducedBipartiteSubgraph {
     }
 }
 
-fn print_vec(vec: &Vec<usize>) -> Vec<usize> {
+fn print_vec(vec: &[usize]) -> Vec<usize> {
     vec.iter().copied().map(|e| e + 1).collect()
 }
 
@@ -319,6 +317,7 @@ pub mod union_find_library {
         }
 
         #[inline(always)]
+        #[allow(clippy::wrong_self_convention)]
         /// Convert UnionFind to Vec\<Vec\<usize\>\>
         pub fn to_vec(&mut self) -> Vec<Vec<usize>> {
             let mut set = vec![Vec::new(); self.par.len()];
@@ -355,7 +354,7 @@ pub mod union_find_library {
                 (0, 0, 0),
                 (1, 0, 0),
             ];
-            let ans = vec![true, false, true, true];
+            let ans = [true, false, true, true];
             let n = 8;
             let mut uf_tree = UnionFind::new(n);
             let mut index = 0;
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+use crate::connected::Connected;
+use crate::maximal_induced_forest::Forest;
+
+/// maximal vertex sets inducing a tree, i.e., the connected version of
+/// [`MaximalInducedForest`](crate::maximal_induced_forest::MaximalInducedForest).
+///
+/// like the forests, `neighbors` branches on cycles and is exponential in the worst case.
+pub type MaximalConnectedInducedTree = Connected<Forest>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_connected_induced_tree() {
+        for edges in random_graphs(9) {
+            let problem = MaximalConnectedInducedTree::init(9, edges, Forest);
+            assert_enumerates(&problem, 9, |set| problem.is_connected_feasible(set));
+        }
+    }
+}
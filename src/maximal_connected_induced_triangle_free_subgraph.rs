@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+use crate::connected::Connected;
+use crate::maximal_induced_triangle_free_subgraph::TriangleFree;
+
+/// maximal vertex sets inducing a connected triangle-free subgraph.
+pub type MaximalConnectedInducedTriangleFreeSubgraph = Connected<TriangleFree>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_connected_induced_triangle_free_subgraph() {
+        for edges in random_graphs(9) {
+            let problem = MaximalConnectedInducedTriangleFreeSubgraph::init(9, edges, TriangleFree);
+            assert_enumerates(&problem, 9, |set| problem.is_connected_feasible(set));
+        }
+    }
+}
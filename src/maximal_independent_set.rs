@@ -0,0 +1,89 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// maximal independent sets of G.
+pub struct MaximalIndependentSets {
+    graph: Graph,
+}
+
+impl MaximalIndependentSets {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        Self {
+            graph: Graph::new(vertices, edges),
+        }
+    }
+
+    /// greedily add vertices in increasing order while the set stays independent
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.graph.vertices {
+            if !component.contains(&n) && self.can_add(&component, n) {
+                component.insert(n);
+            }
+        }
+        component
+    }
+
+    fn can_add(&self, set: &HashSet<usize>, v: usize) -> bool {
+        set.iter().all(|&u| !self.graph.is_adjacent(u, v))
+    }
+
+    fn is_independent(&self, set: &HashSet<usize>) -> bool {
+        set.iter()
+            .all(|&u| set.iter().all(|&v| !self.graph.is_adjacent(u, v)))
+    }
+}
+
+impl ProsimitySearchable for MaximalIndependentSets {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn proximity(solution: &VertexSet, solution_: &VertexSet) -> HashSet<usize> {
+        solution.intersection(solution_)
+    }
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        (0..self.graph.vertices)
+            .filter(|v| !solution.0.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> VertexSet {
+        self.comp(HashSet::new()).into()
+    }
+}
+
+impl CanonicalReconstruction for MaximalIndependentSets {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    /// the only removable set is N(v) ∩ S
+    fn neightbors(&self, solution: &VertexSet, vertex: usize) -> Vec<VertexSet> {
+        let n_v = self.graph.neighborhood(vertex);
+        let mut component = solution
+            .to_set()
+            .difference(&n_v)
+            .copied()
+            .collect::<HashSet<_>>();
+        component.insert(vertex);
+        vec![self.comp(component).into()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_independent_sets() {
+        for edges in random_graphs(9) {
+            let problem = MaximalIndependentSets::init(9, edges);
+            assert_enumerates(&problem, 9, |set| problem.is_independent(set));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+use crate::graph::{set_to_vec, Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use crate::ProsimitySearchable;
+use std::collections::HashSet;
+
+/// the property of inducing a complete bipartite graph (B_0, B_1).
+/// B_1 may be empty, so that the property is hereditary.
+///
+/// G[S] is complete bipartite iff every three vertices of S span zero or two edges,
+/// i.e., G[S] has neither a triangle nor an edge plus an isolated vertex.
+pub struct Biclique;
+
+impl Biclique {
+    /// two vertices a, b of `set` such that v, a, b span one or three edges, if any
+    fn obstruction(graph: &Graph, set: &[usize], v: usize) -> Option<(usize, usize)> {
+        for (i, &a) in set.iter().enumerate() {
+            for &b in &set[i + 1..] {
+                let edges = [(v, a), (v, b), (a, b)]
+                    .iter()
+                    .filter(|&&(x, y)| graph.is_adjacent(x, y))
+                    .count();
+                if edges % 2 == 1 {
+                    return Some((a, b));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Property for Biclique {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        // every bad triple is found when its largest vertex is added
+        (0..set.0.len()).all(|i| Self::obstruction(graph, &set.0[..i], set.0[i]).is_none())
+    }
+
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        Self::obstruction(graph, &set.0, v).is_none()
+    }
+
+    /// the two other vertices of a triple through v spanning one or three edges
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        Self::obstruction(graph, &set.0, v).map(|(a, b)| vec![a, b])
+    }
+}
+
+/// maximal vertex sets inducing a complete bipartite graph.
+pub type MaximalInducedBicliques = HereditaryProblem<Biclique>;
+
+impl MaximalInducedBicliques {
+    /// maximal induced bicliques with at least `min_side` vertices on each side, as sorted
+    /// sides, in increasing order. every solution is enumerated and then filtered, since the
+    /// search needs every solution. `min_side` = 0 keeps the independent sets, whose B_1 is
+    /// empty, and any positive bound excludes them.
+    pub fn large_bicliques(&self, min_side: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+        let mut bicliques = self
+            .enemurate()
+            .iter()
+            .map(|s| self.bipartition(&s.to_set()))
+            .filter(|(b_0, b_1)| b_0.len().min(b_1.len()) >= min_side)
+            .map(|(b_0, b_1)| (set_to_vec(&b_0), set_to_vec(&b_1)))
+            .collect::<Vec<_>>();
+        bicliques.sort();
+        bicliques
+    }
+
+    /// (B_0, B_1) of a complete bipartite G[set], B_0 containing its smallest vertex.
+    /// the sides are the connected components of the complement of G[set].
+    pub fn bipartition(&self, set: &HashSet<usize>) -> (HashSet<usize>, HashSet<usize>) {
+        match set_to_vec(set).first() {
+            Some(&v) => set
+                .iter()
+                .partition(|&&u| u == v || !self.graph.is_adjacent(u, v)),
+            None => (HashSet::new(), HashSet::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_bicliques() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedBicliques::init(9, edges, Biclique);
+            let is_biclique = |set: &HashSet<usize>| {
+                let (b_0, b_1) = problem.bipartition(set);
+                set.iter().all(|&u| {
+                    set.iter().all(|&v| {
+                        problem.graph.is_adjacent(u, v) == (b_0.contains(&u) != b_0.contains(&v))
+                    })
+                }) && b_0.len() + b_1.len() == set.len()
+            };
+            assert_enumerates(&problem, 9, is_biclique);
+        }
+    }
+
+    #[test]
+    fn for_min_side() {
+        // K_{2,3} on {0, 1} x {2, 3, 4}, with the pendant edge (4, 5)
+        let edges = vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (4, 5)]
+            .into_iter()
+            .collect();
+        let problem = MaximalInducedBicliques::init(6, edges, Biclique);
+        assert_eq!(
+            problem.large_bicliques(2),
+            vec![(vec![0, 1], vec![2, 3, 4])]
+        );
+        // {2, 3, 5} is independent, so it is only kept without a bound
+        assert!(problem
+            .large_bicliques(0)
+            .contains(&(vec![2, 3, 5], Vec::new())));
+        assert!(problem
+            .large_bicliques(1)
+            .iter()
+            .all(|(_, b_1)| !b_1.is_empty()));
+    }
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+
+/// the property of inducing a subgraph of maximum degree at most d.
+///
+/// d = 0 gives the independent sets, d = 1 the induced matchings plus isolated vertices.
+pub struct BoundedDegree {
+    d: usize,
+}
+
+impl BoundedDegree {
+    pub fn new(d: usize) -> Self {
+        Self { d }
+    }
+
+    fn degree(graph: &Graph, set: &[usize], v: usize) -> usize {
+        set.iter().filter(|&&u| graph.is_adjacent(u, v)).count()
+    }
+
+    /// a vertex of degree more than d in G[set ∪ {v}] among v and N(v), which are the only
+    /// candidates when `set` is bounded
+    fn overloaded(&self, graph: &Graph, set: &[usize], v: usize) -> Option<usize> {
+        let n_v = set
+            .iter()
+            .copied()
+            .filter(|&u| graph.is_adjacent(u, v))
+            .collect::<Vec<_>>();
+        if n_v.len() > self.d {
+            return Some(v);
+        }
+        // the neighbors of v gain v as a neighbor
+        n_v.into_iter()
+            .find(|&u| Self::degree(graph, set, u) + 1 > self.d)
+    }
+}
+
+impl Property for BoundedDegree {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        set.0
+            .iter()
+            .all(|&v| Self::degree(graph, &set.0, v) <= self.d)
+    }
+
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        self.overloaded(graph, &set.0, v).is_none()
+    }
+
+    /// an overloaded vertex w with its neighbors in S ∪ {v}, since w is only relieved by
+    /// removing w or one of them
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        self.overloaded(graph, &set.0, v).map(|w| {
+            set.0
+                .iter()
+                .copied()
+                .chain([v])
+                .filter(|&u| u == w || graph.is_adjacent(u, w))
+                .collect()
+        })
+    }
+}
+
+/// maximal vertex sets whose induced subgraph has maximum degree at most d.
+pub type MaximalInducedBoundedDegree = HereditaryProblem<BoundedDegree>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_bounded_degree() {
+        for d in 0..=2 {
+            for edges in random_graphs(9) {
+                let problem = MaximalInducedBoundedDegree::init(9, edges, BoundedDegree::new(d));
+                assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+            }
+        }
+    }
+}
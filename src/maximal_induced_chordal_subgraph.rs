@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+use crate::graph::{set_to_vec, Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// the property of inducing a chordal graph, i.e., one without chordless cycles of
+/// length four or more.
+pub struct Chordal;
+
+impl Chordal {
+    /// maximum cardinality search on G[set]: each next vertex has the most already visited
+    /// neighbors, ties being broken by vertex number.
+    /// G[set] is chordal iff the reverse of this order is a perfect elimination ordering.
+    pub fn maximum_cardinality_search(graph: &Graph, set: &HashSet<usize>) -> Vec<usize> {
+        let mut rest = set_to_vec(set);
+        let mut weight = vec![0; graph.vertices];
+        let mut order = Vec::new();
+        while !rest.is_empty() {
+            let i = (0..rest.len())
+                .max_by_key(|&i| (weight[rest[i]], std::cmp::Reverse(rest[i])))
+                .unwrap();
+            let v = rest.remove(i);
+            for &u in &rest {
+                if graph.is_adjacent(u, v) {
+                    weight[u] += 1;
+                }
+            }
+            order.push(v);
+        }
+        order
+    }
+
+    /// for each v, the earlier neighbors of v other than the latest one, p, must be
+    /// adjacent to p
+    pub(crate) fn is_chordal(graph: &Graph, set: &HashSet<usize>) -> bool {
+        let order = Self::maximum_cardinality_search(graph, set);
+        (0..order.len()).all(|i| {
+            let earlier = order[..i]
+                .iter()
+                .copied()
+                .filter(|&u| graph.is_adjacent(u, order[i]))
+                .collect::<Vec<_>>();
+            match earlier.split_last() {
+                Some((&p, rest)) => rest.iter().all(|&u| graph.is_adjacent(u, p)),
+                None => true,
+            }
+        })
+    }
+}
+
+impl Property for Chordal {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        Self::is_chordal(graph, &set.to_set())
+    }
+}
+
+/// maximal vertex sets inducing a chordal graph.
+///
+/// holes may be as long as the graph, so `neighbors` is exponential in the worst case.
+pub type MaximalInducedChordal = HereditaryProblem<Chordal>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_chordal() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedChordal::init(9, edges, Chordal);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_is_chordal() {
+        // 4-cycle 0 - 1 - 2 - 3 - 0, and the chord (0, 2) on demand
+        let cycle = vec![(0, 1), (1, 2), (2, 3), (3, 0)];
+        let all = (0..4).collect::<HashSet<_>>();
+        let graph = Graph::new(4, cycle.iter().copied().collect());
+        assert!(!Chordal::is_chordal(&graph, &all));
+        let chorded = cycle.into_iter().chain([(0, 2)]).collect();
+        let graph = Graph::new(4, chorded);
+        assert!(Chordal::is_chordal(&graph, &all));
+    }
+}
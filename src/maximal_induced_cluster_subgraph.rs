@@ -0,0 +1,103 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// the property of inducing a cluster graph, i.e., a disjoint union of cliques.
+///
+/// a graph is a cluster graph iff it has no induced P3.
+pub struct Cluster;
+
+impl Cluster {
+    /// two vertices a, b of the P3-free `set` that form an induced P3 together with v, if any
+    fn p3(graph: &Graph, set: &[usize], v: usize) -> Option<(usize, usize)> {
+        for &a in set {
+            if !graph.is_adjacent(v, a) {
+                continue;
+            }
+            for &b in set {
+                if a == b {
+                    continue;
+                }
+                // a - v - b
+                if graph.is_adjacent(v, b) && !graph.is_adjacent(a, b) {
+                    return Some((a, b));
+                }
+                // v - a - b
+                if !graph.is_adjacent(v, b) && graph.is_adjacent(a, b) {
+                    return Some((a, b));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Property for Cluster {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        set.0.iter().all(|&u| {
+            set.0.iter().all(|&a| {
+                set.0.iter().all(|&b| {
+                    a == b
+                        || !graph.is_adjacent(u, a)
+                        || !graph.is_adjacent(u, b)
+                        || graph.is_adjacent(a, b)
+                })
+            })
+        })
+    }
+
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        Self::p3(graph, &set.0, v).is_none()
+    }
+
+    /// the two other vertices of an induced P3 created by v
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        Self::p3(graph, &set.0, v).map(|(a, b)| vec![a, b])
+    }
+}
+
+/// maximal vertex sets inducing a cluster graph.
+pub type MaximalInducedClusterSubgraph = HereditaryProblem<Cluster>;
+
+impl MaximalInducedClusterSubgraph {
+    /// the cliques of a solution
+    pub fn clusters(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        let set = solution.to_set();
+        let mut clusters = Vec::new();
+        let mut visited = HashSet::new();
+        for &v in &solution.0 {
+            if visited.insert(v) {
+                let cluster = self.graph.cc(&set, v);
+                visited.extend(cluster.iter().copied());
+                clusters.push(cluster.into());
+            }
+        }
+        clusters
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_cluster_subgraph() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedClusterSubgraph::init(9, edges, Cluster);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_clusters() {
+        // a triangle and an edge, joined by the edge (2, 3)
+        let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4)]
+            .into_iter()
+            .collect();
+        let problem = MaximalInducedClusterSubgraph::init(5, edges, Cluster);
+        let clusters = problem.clusters(&VertexSet(vec![0, 1, 2, 4]));
+        assert_eq!(clusters, vec![VertexSet(vec![0, 1, 2]), VertexSet(vec![4])]);
+    }
+}
@@ -0,0 +1,267 @@
+#![allow(dead_code)]
+use crate::graph::{set_to_vec, Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// cotree of a cograph: leaves are vertices, and an internal node is either the disjoint
+/// union or the join of its children. children of a node are never of its own kind.
+/// every node keeps the set of leaves below it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cotree {
+    pub node: Node,
+    pub leaves: HashSet<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    Leaf(usize),
+    Union(Vec<Cotree>),
+    Join(Vec<Cotree>),
+}
+
+impl Cotree {
+    pub fn leaf(v: usize) -> Cotree {
+        Cotree {
+            node: Node::Leaf(v),
+            leaves: [v].into(),
+        }
+    }
+
+    /// the join of `children` if `join`, otherwise their disjoint union, merging children
+    /// of the same kind
+    fn internal(join: bool, children: Vec<Cotree>) -> Cotree {
+        let mut flat = Vec::new();
+        for child in children {
+            match child.node {
+                Node::Join(grandchildren) if join => flat.extend(grandchildren),
+                Node::Union(grandchildren) if !join => flat.extend(grandchildren),
+                node => flat.push(Cotree {
+                    node,
+                    leaves: child.leaves,
+                }),
+            }
+        }
+        if flat.len() == 1 {
+            return flat.pop().unwrap();
+        }
+        let leaves = flat.iter().flat_map(|c| c.leaves.iter().copied()).collect();
+        let node = if join {
+            Node::Join(flat)
+        } else {
+            Node::Union(flat)
+        };
+        Cotree { node, leaves }
+    }
+
+    /// the number of leaves in `neighbors`, iterating over the smaller of the two
+    fn adjacent(&self, neighbors: &HashSet<usize>) -> usize {
+        if self.leaves.len() <= neighbors.len() {
+            self.leaves.iter().filter(|v| neighbors.contains(v)).count()
+        } else {
+            neighbors.iter().filter(|v| self.leaves.contains(v)).count()
+        }
+    }
+
+    /// add a new vertex x adjacent to the vertices in `neighbors`, and return whether the
+    /// result is a cograph. the cotree is left unchanged otherwise.
+    ///
+    /// incremental recognition in the manner of Corneil, Perl and Stewart, descending from
+    /// the root along the only node that x splits. the cached leaf sets count the
+    /// neighbors of x in a child in time min(|N(x)|, its leaves), without traversing it.
+    /// with that cost per child on every level, and a copied leaf set when x becomes the
+    /// sibling of a whole subtree, this is not linear overall like their marking scheme.
+    pub fn insert(&mut self, x: usize, neighbors: &HashSet<usize>) -> bool {
+        let adjacent = self.adjacent(neighbors);
+        if adjacent == 0 || adjacent == self.leaves.len() {
+            let old = std::mem::replace(self, Cotree::leaf(x));
+            *self = Cotree::internal(adjacent > 0, vec![old, Cotree::leaf(x)]);
+            return true;
+        }
+        let counts = match &self.node {
+            Node::Leaf(_) => unreachable!("a leaf is adjacent to x or not"),
+            Node::Union(children) | Node::Join(children) => children
+                .iter()
+                .map(|c| (c.adjacent(neighbors), c.leaves.len()))
+                .collect::<Vec<_>>(),
+        };
+        let inserted = match &mut self.node {
+            Node::Leaf(_) => unreachable!(),
+            // the component of x consists of x and the children it touches. if there are
+            // several, x is a cut vertex of a connected cograph, so it sees all of them.
+            Node::Union(children) => {
+                let touched = (0..counts.len())
+                    .filter(|&i| counts[i].0 > 0)
+                    .collect::<Vec<_>>();
+                if let [i] = touched[..] {
+                    children[i].insert(x, neighbors)
+                } else if counts.iter().all(|&(a, l)| a == 0 || a == l) {
+                    let (touched, rest) = std::mem::take(children)
+                        .into_iter()
+                        .zip(&counts)
+                        .partition::<Vec<_>, _>(|(_, &(a, _))| a > 0);
+                    let touched = touched.into_iter().map(|(c, _)| c).collect();
+                    *children = rest.into_iter().map(|(c, _)| c).collect();
+                    children.push(Cotree::internal(
+                        true,
+                        vec![Cotree::internal(false, touched), Cotree::leaf(x)],
+                    ));
+                    true
+                } else {
+                    false
+                }
+            }
+            // the same in the complement
+            Node::Join(children) => {
+                let missed = (0..counts.len())
+                    .filter(|&i| counts[i].0 < counts[i].1)
+                    .collect::<Vec<_>>();
+                if let [i] = missed[..] {
+                    children[i].insert(x, neighbors)
+                } else if counts.iter().all(|&(a, l)| a == 0 || a == l) {
+                    let (missed, rest) = std::mem::take(children)
+                        .into_iter()
+                        .zip(&counts)
+                        .partition::<Vec<_>, _>(|(_, &(a, l))| a < l);
+                    let missed = missed.into_iter().map(|(c, _)| c).collect();
+                    *children = rest.into_iter().map(|(c, _)| c).collect();
+                    children.push(Cotree::internal(
+                        false,
+                        vec![Cotree::internal(true, missed), Cotree::leaf(x)],
+                    ));
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+        if inserted {
+            self.leaves.insert(x);
+        }
+        inserted
+    }
+}
+
+/// the property of inducing a cograph, i.e., a P4-free graph.
+pub struct Cograph;
+
+impl Cograph {
+    /// cotree of G[set], or None if G[set] is empty or not a cograph,
+    /// built by inserting the vertices one by one
+    pub fn cotree(graph: &Graph, set: &[usize]) -> Option<Cotree> {
+        let (&first, rest) = set.split_first()?;
+        let mut cotree = Cotree::leaf(first);
+        rest.iter()
+            .all(|&v| cotree.insert(v, &graph.neighborhood(v)))
+            .then_some(cotree)
+    }
+
+    /// three vertices of `set` inducing a P4 together with v, if any
+    fn p4(graph: &Graph, set: &[usize], v: usize) -> Option<[usize; 3]> {
+        for (i, &a) in set.iter().enumerate() {
+            for (j, &b) in set.iter().enumerate().skip(i + 1) {
+                for &c in &set[j + 1..] {
+                    if Self::is_p4(graph, [v, a, b, c]) {
+                        return Some([a, b, c]);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// four vertices induce a P4 iff they span three edges with degrees 1, 1, 2, 2
+    fn is_p4(graph: &Graph, quad: [usize; 4]) -> bool {
+        let mut degrees = quad.map(|u| {
+            quad.iter()
+                .filter(|&&w| w != u && graph.is_adjacent(u, w))
+                .count()
+        });
+        degrees.sort();
+        degrees == [1, 1, 2, 2]
+    }
+}
+
+impl Property for Cograph {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        set.0.is_empty() || Self::cotree(graph, &set.0).is_some()
+    }
+
+    /// insert v into the cotree of `set`
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        match Self::cotree(graph, &set.0) {
+            Some(mut cotree) => cotree.insert(v, &graph.neighborhood(v)),
+            None => true,
+        }
+    }
+
+    /// the three other vertices of a P4 through v
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        Self::p4(graph, &set.0, v).map(Vec::from)
+    }
+}
+
+/// maximal vertex sets inducing a cograph, i.e., a P4-free graph.
+pub type MaximalInducedCograph = HereditaryProblem<Cograph>;
+
+impl MaximalInducedCograph {
+    /// cotree of G[set], or None if G[set] is empty or not a cograph
+    pub fn cotree(&self, set: &HashSet<usize>) -> Option<Cotree> {
+        Cograph::cotree(&self.graph, &set_to_vec(set))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_cograph() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedCograph::init(9, edges, Cograph);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+
+    #[test]
+    fn for_cotree() {
+        let edges = random_edges(8, 0);
+        let problem = MaximalInducedCograph::init(8, edges, Cograph);
+        for mask in 0..1usize << 8 {
+            let set = (0..8)
+                .filter(|i| mask >> i & 1 == 1)
+                .collect::<HashSet<_>>();
+            let p4_free = set.iter().all(|&v| {
+                let rest = set.iter().copied().filter(|&u| u != v).collect();
+                Cograph::p4(&problem.graph, &set_to_vec(&rest), v).is_none()
+            });
+            assert_eq!(problem.is_feasible(&set), p4_free);
+            // two leaves are adjacent iff their lowest common ancestor is a join
+            if let Some(cotree) = problem.cotree(&set) {
+                assert_eq!(cotree.leaves, set);
+                for &u in &set {
+                    for &v in &set {
+                        if u != v {
+                            assert_eq!(joined(&cotree, u, v), problem.graph.is_adjacent(u, v));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn joined(cotree: &Cotree, u: usize, v: usize) -> bool {
+        match &cotree.node {
+            Node::Leaf(_) => unreachable!(),
+            Node::Union(children) | Node::Join(children) => {
+                let both = children
+                    .iter()
+                    .find(|c| c.leaves.contains(&u) && c.leaves.contains(&v));
+                match both {
+                    Some(child) => joined(child, u, v),
+                    None => matches!(cotree.node, Node::Join(_)),
+                }
+            }
+        }
+    }
+}
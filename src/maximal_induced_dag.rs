@@ -0,0 +1,169 @@
+#![allow(dead_code)]
+use crate::graph::{removable_sets, DiGraph, VertexSet};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::{HashSet, VecDeque};
+
+/// maximal vertex sets inducing a directed acyclic graph.
+///
+/// V(G) \ S is a minimal directed feedback vertex set for every solution S.
+/// the obstructions are directed cycles of any length, so `neighbors` is exponential in
+/// the worst case.
+pub struct MaximalInducedDag {
+    graph: DiGraph,
+}
+
+impl MaximalInducedDag {
+    pub fn init(vertices: usize, arcs: HashSet<(usize, usize)>) -> Self {
+        Self {
+            graph: DiGraph::new(vertices, arcs),
+        }
+    }
+
+    /// all minimal directed feedback vertex sets, as complements of the maximal induced DAGs
+    pub fn minimal_feedback_vertex_sets(&self) -> HashSet<VertexSet> {
+        self.enemurate()
+            .into_iter()
+            .map(|s| {
+                (0..self.graph.vertices)
+                    .filter(|v| !s.0.contains(v))
+                    .collect::<HashSet<_>>()
+                    .into()
+            })
+            .collect()
+    }
+
+    /// greedily add vertices in increasing order while the set stays acyclic
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.graph.vertices {
+            if !component.contains(&n) && self.closed_cycle(&component, n).is_none() {
+                component.insert(n);
+            }
+        }
+        component
+    }
+
+    fn is_dag(&self, set: &HashSet<usize>) -> bool {
+        let mut rest = HashSet::new();
+        for &v in set {
+            if self.closed_cycle(&rest, v).is_some() {
+                return false;
+            }
+            rest.insert(v);
+        }
+        true
+    }
+
+    /// the vertices of `set` on a directed cycle through v in G[set ∪ {v}], if any.
+    /// `set` is assumed acyclic, so this is the incremental check for adding v.
+    fn closed_cycle(&self, set: &HashSet<usize>, v: usize) -> Option<Vec<usize>> {
+        if self.graph.has_arc(v, v) {
+            return Some(vec![]);
+        }
+        let mut parent = vec![None; self.graph.vertices];
+        let mut queue = VecDeque::new();
+        for &u in set {
+            if self.graph.has_arc(v, u) {
+                parent[u] = Some(u);
+                queue.push_back(u);
+            }
+        }
+        while let Some(u) = queue.pop_front() {
+            if self.graph.has_arc(u, v) {
+                let mut path = vec![u];
+                let mut w = u;
+                while parent[w] != Some(w) {
+                    w = parent[w].unwrap();
+                    path.push(w);
+                }
+                return Some(path);
+            }
+            for &w in set {
+                if parent[w].is_none() && self.graph.has_arc(u, w) {
+                    parent[w] = Some(u);
+                    queue.push_back(w);
+                }
+            }
+        }
+        None
+    }
+
+    /// all minimal X ⊆ S such that S ∪ {v} \ X is acyclic,
+    /// found by branching on a vertex of each cycle closed by v.
+    fn removable_sets(&self, solution: &HashSet<usize>, v: usize) -> Vec<HashSet<usize>> {
+        removable_sets(solution, v, |rest, v| self.closed_cycle(rest, v))
+    }
+}
+
+impl ProsimitySearchable for MaximalInducedDag {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn proximity(solution: &VertexSet, solution_: &VertexSet) -> HashSet<usize> {
+        solution.intersection(solution_)
+    }
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        (0..self.graph.vertices)
+            .filter(|v| !solution.0.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> VertexSet {
+        self.comp(HashSet::new()).into()
+    }
+}
+
+impl CanonicalReconstruction for MaximalInducedDag {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    /// removable sets are the minimal vertex sets of S breaking every cycle closed by v
+    fn neightbors(&self, solution: &VertexSet, vertex: usize) -> Vec<VertexSet> {
+        let solution = solution.to_set();
+        self.removable_sets(&solution, vertex)
+            .into_iter()
+            .map(|x| {
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(vertex);
+                self.comp(component).into()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_dag() {
+        for (seed, forward) in random_graphs(9).enumerate() {
+            // arcs u -> v with u < v from the graph, and arcs v -> u with probability 1/2
+            let backward = random_edges(9, seed as u64 + 100);
+            let arcs = forward
+                .into_iter()
+                .chain(backward.into_iter().map(|(u, v)| (v, u)))
+                .collect();
+            let problem = MaximalInducedDag::init(9, arcs);
+            assert_enumerates(&problem, 9, |set| problem.is_dag(set));
+        }
+    }
+
+    #[test]
+    fn for_minimal_feedback_vertex_sets() {
+        // cycles 0 -> 1 -> 2 -> 0 and 2 -> 3 -> 2, and the self-loop on 4
+        let arcs = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 2), (4, 4)]
+            .into_iter()
+            .collect();
+        let problem = MaximalInducedDag::init(5, arcs);
+        let expected = [vec![2, 4], vec![0, 3, 4], vec![1, 3, 4]]
+            .into_iter()
+            .map(VertexSet)
+            .collect::<HashSet<_>>();
+        assert_eq!(problem.minimal_feedback_vertex_sets(), expected);
+    }
+}
@@ -0,0 +1,197 @@
+#![allow(dead_code)]
+use crate::graph::{removable_sets, Graph, VertexSet};
+use crate::hereditary::Property;
+use crate::maximal_connected_induced_bipartile_subgraph::union_find_library::UnionFind;
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::{HashSet, VecDeque};
+
+/// the property of inducing a forest, shared with the connected version
+/// [`MaximalConnectedInducedTree`](crate::maximal_connected_induced_tree::MaximalConnectedInducedTree).
+pub struct Forest;
+
+impl Forest {
+    pub(crate) fn is_forest(graph: &Graph, set: &HashSet<usize>) -> bool {
+        let mut tree = UnionFind::new(graph.vertices);
+        for &u in set {
+            for &v in set {
+                if u < v && graph.is_adjacent(u, v) && !tree.unite(u, v) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// the path a, ..., b in the forest `set` between two neighbors a, b of v, if any.
+    /// together with v, it is a cycle closed by v.
+    fn closed_cycle(graph: &Graph, set: &HashSet<usize>, v: usize) -> Option<Vec<usize>> {
+        let n_v = set
+            .iter()
+            .copied()
+            .filter(|&u| graph.is_adjacent(u, v))
+            .collect::<HashSet<_>>();
+        for &a in &n_v {
+            let mut parent = vec![None; graph.vertices];
+            parent[a] = Some(a);
+            let mut queue = VecDeque::new();
+            queue.push_back(a);
+            while let Some(u) = queue.pop_front() {
+                if u != a && n_v.contains(&u) {
+                    let mut path = vec![u];
+                    let mut w = u;
+                    while w != a {
+                        w = parent[w].unwrap();
+                        path.push(w);
+                    }
+                    return Some(path);
+                }
+                for &w in set {
+                    if parent[w].is_none() && graph.is_adjacent(u, w) {
+                        parent[w] = Some(u);
+                        queue.push_back(w);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Property for Forest {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        Self::is_forest(graph, &set.to_set())
+    }
+
+    /// a cycle closed by v
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        Self::closed_cycle(graph, &set.to_set(), v)
+    }
+}
+
+/// maximal vertex sets inducing a forest.
+///
+/// V(G) \ S is a minimal feedback vertex set for every solution S.
+/// the obstructions are cycles, which may be long, so `neighbors` is exponential in the
+/// worst case.
+pub struct MaximalInducedForest {
+    pub(crate) graph: Graph,
+}
+
+impl MaximalInducedForest {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        Self {
+            graph: Graph::new(vertices, edges),
+        }
+    }
+
+    /// all minimal feedback vertex sets, as complements of the maximal induced forests
+    pub fn minimal_feedback_vertex_sets(&self) -> HashSet<VertexSet> {
+        self.enemurate()
+            .into_iter()
+            .map(|s| {
+                (0..self.graph.vertices)
+                    .filter(|v| !s.0.contains(v))
+                    .collect::<HashSet<_>>()
+                    .into()
+            })
+            .collect()
+    }
+
+    /// greedily add vertices in increasing order while the set stays acyclic
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.graph.vertices {
+            if component.contains(&n) {
+                continue;
+            }
+            component.insert(n);
+            if !self.is_forest(&component) {
+                component.remove(&n);
+            }
+        }
+        component
+    }
+
+    pub(crate) fn is_forest(&self, set: &HashSet<usize>) -> bool {
+        Forest::is_forest(&self.graph, set)
+    }
+
+    /// all minimal X ⊆ S such that S ∪ {v} \ X is a forest,
+    /// found by branching on a vertex of each cycle closed by v.
+    pub(crate) fn removable_sets(
+        &self,
+        solution: &HashSet<usize>,
+        v: usize,
+    ) -> Vec<HashSet<usize>> {
+        removable_sets(solution, v, |rest, v| {
+            Forest::closed_cycle(&self.graph, rest, v)
+        })
+    }
+}
+
+impl ProsimitySearchable for MaximalInducedForest {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn proximity(solution: &VertexSet, solution_: &VertexSet) -> HashSet<usize> {
+        solution.intersection(solution_)
+    }
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        (0..self.graph.vertices)
+            .filter(|v| !solution.0.contains(v))
+            .flat_map(|v| self.neightbors(solution, v))
+            .collect()
+    }
+
+    fn start(&self) -> VertexSet {
+        self.comp(HashSet::new()).into()
+    }
+}
+
+impl CanonicalReconstruction for MaximalInducedForest {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    /// removable sets are the minimal vertex sets of S breaking every cycle closed by v
+    fn neightbors(&self, solution: &VertexSet, vertex: usize) -> Vec<VertexSet> {
+        let solution = solution.to_set();
+        self.removable_sets(&solution, vertex)
+            .into_iter()
+            .map(|x| {
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(vertex);
+                self.comp(component).into()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_forest() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedForest::init(9, edges);
+            assert_enumerates(&problem, 9, |set| problem.is_forest(set));
+        }
+    }
+
+    #[test]
+    fn for_minimal_feedback_vertex_sets() {
+        // two triangles sharing the vertex 2
+        let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2)]
+            .into_iter()
+            .collect();
+        let problem = MaximalInducedForest::init(5, edges);
+        let expected = [vec![2], vec![0, 3], vec![0, 4], vec![1, 3], vec![1, 4]]
+            .into_iter()
+            .map(VertexSet)
+            .collect::<HashSet<_>>();
+        assert_eq!(problem.minimal_feedback_vertex_sets(), expected);
+    }
+}
@@ -0,0 +1,248 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// the largest forbidden pattern, since occurrences are found by backtracking
+pub const MAX_PATTERN_VERTICES: usize = 5;
+
+/// a small forbidden induced subgraph H.
+///
+/// given by an edge list or parsed from graph6, e.g., "Bg" (P3), "Bw" (K3),
+/// "Ch" (P4) or "Cs" (the claw K1,3).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    adjacency: Vec<Vec<bool>>,
+}
+
+impl Pattern {
+    pub fn from_edges(vertices: usize, edges: &[(usize, usize)]) -> Self {
+        assert!(
+            (1..=MAX_PATTERN_VERTICES).contains(&vertices),
+            "patterns have 1 to {MAX_PATTERN_VERTICES} vertices"
+        );
+        let mut adjacency = vec![vec![false; vertices]; vertices];
+        for &(u, v) in edges {
+            if u != v {
+                adjacency[u][v] = true;
+                adjacency[v][u] = true;
+            }
+        }
+        Self { adjacency }
+    }
+
+    pub fn vertices(&self) -> usize {
+        self.adjacency.len()
+    }
+}
+
+impl std::str::FromStr for Pattern {
+    type Err = String;
+
+    /// graph6: one character n + 63, then the upper triangle of the adjacency matrix
+    /// column by column, x(0, 1), x(0, 2), x(1, 2), x(0, 3), ..., six bits per character
+    /// plus 63, most significant bit first
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.trim().as_bytes();
+        if let Some(&c) = bytes.iter().find(|&&c| !(63..=126).contains(&c)) {
+            return Err(format!("unexpected character {:?} in {s:?}", c as char));
+        }
+        let (&first, data) = bytes.split_first().ok_or("empty graph6 string")?;
+        let vertices = (first - 63) as usize;
+        if !(1..=MAX_PATTERN_VERTICES).contains(&vertices) {
+            return Err(format!(
+                "pattern with {vertices} vertices, 1 to {MAX_PATTERN_VERTICES} are supported"
+            ));
+        }
+        let bits = vertices * vertices.saturating_sub(1) / 2;
+        if data.len() != bits.div_ceil(6) {
+            return Err(format!(
+                "{} data characters for {vertices} vertices, expected {}",
+                data.len(),
+                bits.div_ceil(6)
+            ));
+        }
+        let mut edges = Vec::new();
+        let mut k = 0;
+        for v in 1..vertices {
+            for u in 0..v {
+                if (data[k / 6] - 63) >> (5 - k % 6) & 1 == 1 {
+                    edges.push((u, v));
+                }
+                k += 1;
+            }
+        }
+        Ok(Self::from_edges(vertices, &edges))
+    }
+}
+
+/// the property of containing none of the patterns as an induced subgraph
+pub struct HFree {
+    patterns: Vec<Pattern>,
+}
+
+impl HFree {
+    pub fn new(patterns: Vec<Pattern>) -> Self {
+        Self { patterns }
+    }
+
+    /// the vertices of an induced copy of some pattern in G[candidates ∪ {v}] using v, if any.
+    /// `candidates` is sorted and does not contain v.
+    fn occurrence(&self, graph: &Graph, candidates: &[usize], v: usize) -> Option<Vec<usize>> {
+        self.patterns.iter().find_map(|pattern| {
+            (0..pattern.vertices()).find_map(|a| {
+                // pattern vertices are mapped in the order a, then the others
+                let order = std::iter::once(a)
+                    .chain((0..pattern.vertices()).filter(|&b| b != a))
+                    .collect::<Vec<_>>();
+                let mut image = vec![v];
+                Self::embed(graph, pattern, &order, candidates, &mut image).then_some(image)
+            })
+        })
+    }
+
+    /// extend `image`, the images of a prefix of `order`, to an induced copy of `pattern`
+    fn embed(
+        graph: &Graph,
+        pattern: &Pattern,
+        order: &[usize],
+        candidates: &[usize],
+        image: &mut Vec<usize>,
+    ) -> bool {
+        let i = image.len();
+        if i == order.len() {
+            return true;
+        }
+        for &u in candidates {
+            if image.contains(&u) {
+                continue;
+            }
+            let consistent = (0..i)
+                .all(|j| pattern.adjacency[order[i]][order[j]] == graph.is_adjacent(u, image[j]));
+            if consistent {
+                image.push(u);
+                if Self::embed(graph, pattern, order, candidates, image) {
+                    return true;
+                }
+                image.pop();
+            }
+        }
+        false
+    }
+}
+
+impl Property for HFree {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        // every occurrence is found when its largest vertex is added
+        (0..set.0.len()).all(|i| self.occurrence(graph, &set.0[..i], set.0[i]).is_none())
+    }
+
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        self.occurrence(graph, &set.0, v).is_none()
+    }
+
+    /// an occurrence through v, so that the removable sets hit every occurrence through v
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        self.occurrence(graph, &set.0, v)
+    }
+}
+
+/// maximal vertex sets inducing an H-free subgraph for a finite set of forbidden patterns,
+/// e.g., {P3} for cluster graphs, {P4} for cographs, {K3} for triangle-free graphs and
+/// {K1,3} for claw-free graphs.
+pub type MaximalInducedHFreeSubgraph = HereditaryProblem<HFree>;
+
+impl MaximalInducedHFreeSubgraph {
+    /// patterns given as graph6 strings
+    pub fn from_graph6(
+        vertices: usize,
+        edges: HashSet<(usize, usize)>,
+        patterns: &[&str],
+    ) -> Result<Self, String> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| pattern.parse())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::init(vertices, edges, HFree::new(patterns)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::connected::Connected;
+    use crate::maximal_induced_cluster_subgraph::{Cluster, MaximalInducedClusterSubgraph};
+    use crate::maximal_induced_cograph::{Cograph, MaximalInducedCograph};
+    use crate::maximal_induced_triangle_free_subgraph::{
+        MaximalInducedTriangleFreeSubgraph, TriangleFree,
+    };
+    use crate::ProsimitySearchable;
+
+    #[test]
+    fn for_graph6() {
+        let patterns = [
+            ("Bg", Pattern::from_edges(3, &[(0, 1), (1, 2)])),
+            ("Bw", Pattern::from_edges(3, &[(0, 1), (1, 2), (0, 2)])),
+            ("Ch", Pattern::from_edges(4, &[(0, 1), (1, 2), (2, 3)])),
+            ("Cs", Pattern::from_edges(4, &[(0, 1), (0, 2), (0, 3)])),
+            (
+                "DQK",
+                Pattern::from_edges(5, &[(0, 2), (1, 3), (2, 4), (3, 4)]),
+            ),
+        ];
+        for (graph6, pattern) in patterns {
+            assert_eq!(graph6.parse::<Pattern>(), Ok(pattern));
+        }
+        assert!("F??????".parse::<Pattern>().is_err());
+        assert!("Ch?".parse::<Pattern>().is_err());
+        assert!("".parse::<Pattern>().is_err());
+        assert!("?".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn for_known_classes() {
+        for edges in random_graphs(9) {
+            let cluster = MaximalInducedHFreeSubgraph::from_graph6(9, edges.clone(), &["Bg"]);
+            assert_eq!(
+                cluster.unwrap().enemurate(),
+                MaximalInducedClusterSubgraph::init(9, edges.clone(), Cluster).enemurate()
+            );
+            let cograph = MaximalInducedHFreeSubgraph::from_graph6(9, edges.clone(), &["Ch"]);
+            assert_eq!(
+                cograph.unwrap().enemurate(),
+                MaximalInducedCograph::init(9, edges.clone(), Cograph).enemurate()
+            );
+            let triangle_free = MaximalInducedHFreeSubgraph::from_graph6(9, edges.clone(), &["Bw"]);
+            assert_eq!(
+                triangle_free.unwrap().enemurate(),
+                MaximalInducedTriangleFreeSubgraph::init(9, edges, TriangleFree).enemurate()
+            );
+        }
+    }
+
+    #[test]
+    fn for_maximal_induced_h_free_subgraph() {
+        // claw-free, and {claw, K3}-free
+        for patterns in [vec!["Cs"], vec!["Cs", "Bw"]] {
+            for edges in random_graphs(9) {
+                let problem =
+                    MaximalInducedHFreeSubgraph::from_graph6(9, edges, &patterns).unwrap();
+                assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+            }
+        }
+    }
+
+    #[test]
+    fn for_connected_claw_free() {
+        for edges in random_graphs(9) {
+            let h_free = HFree::new(vec!["Cs".parse().unwrap()]);
+            let graph = Graph::new(9, edges.clone());
+            let problem = Connected::init(9, edges, h_free);
+            let claw_free = HFree::new(vec!["Cs".parse().unwrap()]);
+            assert_enumerates(&problem, 9, |set| {
+                graph.is_connected(set) && claw_free.is_feasible(&graph, &set.clone().into())
+            });
+        }
+    }
+}
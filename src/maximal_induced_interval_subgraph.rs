@@ -0,0 +1,323 @@
+#![allow(dead_code)]
+use crate::graph::{print_vec, set_to_vec, Graph, VertexSet};
+use crate::hereditary::{Certificate, HereditaryProblem, Property};
+use crate::maximal_induced_chordal_subgraph::Chordal;
+use std::collections::HashSet;
+
+/// a vertex set inducing an interval graph, with an interval model as a certificate:
+/// the i-th vertex gets the interval `intervals[i]`, and two vertices are adjacent iff
+/// their intervals intersect. the model is a function of the vertex set.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalModel {
+    pub vertices: Vec<usize>,
+    pub intervals: Vec<(usize, usize)>,
+}
+
+impl IntervalModel {
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.vertices.iter().copied().collect()
+    }
+}
+
+impl std::fmt::Display for IntervalModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {:?}", print_vec(&self.vertices), self.intervals)
+    }
+}
+
+impl Certificate<Interval> for IntervalModel {
+    fn certify(graph: &Graph, property: &Interval, set: &HashSet<usize>) -> Self {
+        property.interval_model(graph, set).unwrap()
+    }
+
+    fn to_set(&self) -> HashSet<usize> {
+        IntervalModel::to_set(self)
+    }
+}
+
+/// the property of inducing an interval graph.
+///
+/// a graph is an interval graph iff it is chordal and its maximal cliques can be ordered
+/// so that the cliques containing each vertex are consecutive (Gilmore and Hoffman),
+/// i.e., the vertex-clique matrix has the consecutive-ones property.
+pub struct Interval;
+
+impl Interval {
+    /// interval model of G[set], or None if G[set] is not an interval graph.
+    /// interval endpoints are positions in the ordering of the maximal cliques.
+    pub fn interval_model(&self, graph: &Graph, set: &HashSet<usize>) -> Option<IntervalModel> {
+        if !Chordal::is_chordal(graph, set) {
+            return None;
+        }
+        let cliques = Self::maximal_cliques(graph, set);
+        let vertices = set_to_vec(set);
+        let rows = vertices
+            .iter()
+            .map(|v| {
+                (0..cliques.len())
+                    .filter(|&c| cliques[c].contains(v))
+                    .collect()
+            })
+            .collect::<Vec<_>>();
+        let order = consecutive_ones(&(0..cliques.len()).collect::<Vec<_>>(), &rows)?;
+        // a model and its mirror image describe the same set, so keep the smaller order
+        let reversed = order.iter().rev().copied().collect::<Vec<_>>();
+        let order = order.min(reversed);
+        let intervals = vertices
+            .iter()
+            .map(|v| {
+                let positions = order
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &c)| cliques[c].contains(v))
+                    .map(|(i, _)| i);
+                let left = positions.clone().min().unwrap();
+                (left, positions.max().unwrap())
+            })
+            .collect();
+        Some(IntervalModel {
+            vertices,
+            intervals,
+        })
+    }
+
+    /// maximal cliques of the chordal G[set]: each vertex together with its neighbors
+    /// visited earlier by maximum cardinality search, keeping the maximal ones
+    fn maximal_cliques(graph: &Graph, set: &HashSet<usize>) -> Vec<HashSet<usize>> {
+        let order = Chordal::maximum_cardinality_search(graph, set);
+        let mut cliques: Vec<HashSet<usize>> = Vec::new();
+        for (i, &v) in order.iter().enumerate() {
+            let mut clique = order[..i]
+                .iter()
+                .copied()
+                .filter(|&u| graph.is_adjacent(u, v))
+                .collect::<HashSet<_>>();
+            clique.insert(v);
+            cliques.retain(|c| !c.is_subset(&clique));
+            if !cliques.iter().any(|c| clique.is_subset(c)) {
+                cliques.push(clique);
+            }
+        }
+        cliques
+    }
+}
+
+impl Property for Interval {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        self.interval_model(graph, &set.to_set()).is_some()
+    }
+}
+
+/// maximal vertex sets inducing an interval graph, each with its interval model.
+///
+/// the minimal non-interval graphs include holes and long asteroidal structures, so the
+/// branching in `neighbors` is exponential in the worst case.
+pub type MaximalInducedIntervalSubgraph = HereditaryProblem<Interval, IntervalModel>;
+
+impl MaximalInducedIntervalSubgraph {
+    /// interval model of G[set], or None if G[set] is not an interval graph
+    pub fn interval_model(&self, set: &HashSet<usize>) -> Option<IntervalModel> {
+        self.property.interval_model(&self.graph, set)
+    }
+}
+
+/// an ordering of `columns` in which every row is consecutive, if any.
+///
+/// consecutive-ones test by partition refinement, which computes the Q-nodes of the
+/// PQ-tree one level at a time. each pass over the rows splits a class or ends the
+/// level, so it takes polynomial time, unlike trying orders of the cliques:
+/// - rows linking no two columns, or all of them, impose nothing.
+/// - if the rows split the columns into several groups, each is ordered on its own.
+/// - otherwise the inclusion-maximal rows overlap in a connected way, and placing them one
+///   by one fixes an ordered partition of the columns, up to reversal. every other row
+///   spanning several classes refines the classes at its ends, and the rows left inside
+///   a class only constrain that class.
+fn consecutive_ones(columns: &[usize], rows: &[HashSet<usize>]) -> Option<Vec<usize>> {
+    let mut constraints: Vec<HashSet<usize>> = Vec::new();
+    for row in rows {
+        if 1 < row.len() && row.len() < columns.len() && !constraints.contains(row) {
+            constraints.push(row.clone());
+        }
+    }
+    if constraints.is_empty() {
+        return Some(columns.to_vec());
+    }
+    let rows_in = |part: &HashSet<usize>| {
+        constraints
+            .iter()
+            .filter(|row| row.is_subset(part))
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+    let groups = groups(columns, &constraints);
+    if groups.len() > 1 {
+        let mut order = Vec::new();
+        for group in groups {
+            order.extend(consecutive_ones(&set_to_vec(&group), &rows_in(&group))?);
+        }
+        return Some(order);
+    }
+    let maximal = constraints
+        .iter()
+        .filter(|row| {
+            !constraints
+                .iter()
+                .any(|other| row != &other && row.is_subset(other))
+        })
+        .collect::<Vec<_>>();
+    let mut classes = vec![maximal[0].clone()];
+    let mut covered = maximal[0].clone();
+    let mut placed = vec![false; maximal.len()];
+    placed[0] = true;
+    while let Some(i) =
+        (0..maximal.len()).find(|&i| !placed[i] && !maximal[i].is_disjoint(&covered))
+    {
+        placed[i] = true;
+        let new = maximal[i]
+            .difference(&covered)
+            .copied()
+            .collect::<HashSet<_>>();
+        if new.is_empty() {
+            refine(&mut classes, maximal[i])?;
+        } else {
+            extend(&mut classes, maximal[i], new.clone())?;
+            covered.extend(new);
+        }
+    }
+    while constraints
+        .iter()
+        .map(|row| refine(&mut classes, row))
+        .collect::<Option<Vec<_>>>()?
+        .contains(&true)
+    {}
+    let mut order = Vec::new();
+    for class in classes {
+        order.extend(consecutive_ones(&set_to_vec(&class), &rows_in(&class))?);
+    }
+    Some(order)
+}
+
+/// the classes touched by `row`, which must be consecutive, with all but the two at the
+/// ends contained in `row`
+fn touched(classes: &[HashSet<usize>], row: &HashSet<usize>) -> Option<(usize, usize)> {
+    let touched = (0..classes.len())
+        .filter(|&i| !classes[i].is_disjoint(row))
+        .collect::<Vec<_>>();
+    let (&first, &last) = (touched.first()?, touched.last()?);
+    let consecutive = last - first + 1 == touched.len();
+    let inner_full = (first + 1..last).all(|i| classes[i].is_subset(row));
+    (consecutive && inner_full).then_some((first, last))
+}
+
+/// split the end classes of a row spanning several classes so that the row becomes a run
+/// of whole classes. returns whether anything changed, or None if the row cannot be
+/// consecutive.
+fn refine(classes: &mut Vec<HashSet<usize>>, row: &HashSet<usize>) -> Option<bool> {
+    if row
+        .iter()
+        .all(|c| !classes.iter().any(|class| class.contains(c)))
+    {
+        return Some(false);
+    }
+    let (first, last) = touched(classes, row)?;
+    if first == last {
+        return Some(false);
+    }
+    let mut changed = false;
+    if !classes[last].is_subset(row) {
+        let (inside, outside) = classes[last].iter().partition(|c| row.contains(c));
+        classes.splice(last..=last, [inside, outside]);
+        changed = true;
+    }
+    if !classes[first].is_subset(row) {
+        let (inside, outside) = classes[first].iter().partition(|c| row.contains(c));
+        classes.splice(first..=first, [outside, inside]);
+        changed = true;
+    }
+    Some(changed)
+}
+
+/// place a row reaching the columns `new` outside the classes, which must go past one end
+fn extend(
+    classes: &mut Vec<HashSet<usize>>,
+    row: &HashSet<usize>,
+    new: HashSet<usize>,
+) -> Option<()> {
+    let (first, last) = touched(classes, row)?;
+    let end = classes.len() - 1;
+    if last == end && (first == last || classes[last].is_subset(row)) {
+        if !classes[first].is_subset(row) {
+            let (inside, outside) = classes[first].iter().partition(|c| row.contains(c));
+            classes.splice(first..=first, [outside, inside]);
+        }
+        classes.push(new);
+    } else if first == 0 && (first == last || classes[first].is_subset(row)) {
+        if !classes[last].is_subset(row) {
+            let (inside, outside) = classes[last].iter().partition(|c| row.contains(c));
+            classes.splice(last..=last, [inside, outside]);
+        }
+        classes.insert(0, new);
+    } else {
+        return None;
+    }
+    Some(())
+}
+
+/// the columns grouped by sharing rows
+fn groups(columns: &[usize], rows: &[HashSet<usize>]) -> Vec<HashSet<usize>> {
+    let mut groups: Vec<HashSet<usize>> = columns.iter().map(|&c| [c].into()).collect();
+    for row in rows {
+        let (linked, mut rest): (Vec<_>, Vec<_>) = groups
+            .into_iter()
+            .partition(|group| !group.is_disjoint(row));
+        rest.push(linked.into_iter().flatten().collect());
+        groups = rest;
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_edges, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_interval_subgraph() {
+        for edges in random_graphs(8) {
+            let problem = MaximalInducedIntervalSubgraph::with_certificates(8, edges, Interval);
+            // also checks that each vertex set gets a single model
+            assert_enumerates(&problem, 8, |set| problem.interval_model(set).is_some());
+        }
+    }
+
+    #[test]
+    fn for_interval_model() {
+        let problem =
+            MaximalInducedIntervalSubgraph::with_certificates(8, random_edges(8, 0), Interval);
+        for mask in 0..1usize << 8 {
+            let set = (0..8)
+                .filter(|i| mask >> i & 1 == 1)
+                .collect::<HashSet<_>>();
+            if let Some(model) = problem.interval_model(&set) {
+                for (i, &u) in model.vertices.iter().enumerate() {
+                    for (j, &v) in model.vertices.iter().enumerate() {
+                        let (a, b) = (model.intervals[i], model.intervals[j]);
+                        let intersect = a.0 <= b.1 && b.0 <= a.1;
+                        assert_eq!(i == j || problem.graph.is_adjacent(u, v), intersect);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn for_asteroidal_triple() {
+        // the subdivided claw is chordal but not an interval graph
+        let edges = vec![(0, 1), (1, 2), (0, 3), (3, 4), (0, 5), (5, 6)]
+            .into_iter()
+            .collect();
+        let problem = MaximalInducedIntervalSubgraph::with_certificates(7, edges, Interval);
+        assert!(problem.interval_model(&(0..7).collect()).is_none());
+        assert!(problem.interval_model(&(0..6).collect()).is_some());
+    }
+}
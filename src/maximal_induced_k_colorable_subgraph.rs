@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+use crate::graph::{set_to_vec, Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// the property of inducing a k-colorable subgraph. k = 2 is the bipartite case.
+pub struct KColorable {
+    k: usize,
+}
+
+impl KColorable {
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0, "k must be positive");
+        Self { k }
+    }
+
+    /// color classes C_0, ..., C_{k-1} of G[set], found by backtracking
+    pub fn coloring(&self, graph: &Graph, set: &HashSet<usize>) -> Option<Vec<HashSet<usize>>> {
+        let vertices = set_to_vec(set);
+        let mut colors = vec![0; vertices.len()];
+        if !self.assign(graph, &vertices, &mut colors, 0) {
+            return None;
+        }
+        let mut classes = vec![HashSet::new(); self.k];
+        for (&v, &c) in vertices.iter().zip(&colors) {
+            classes[c].insert(v);
+        }
+        Some(classes)
+    }
+
+    fn assign(&self, graph: &Graph, vertices: &[usize], colors: &mut [usize], i: usize) -> bool {
+        if i == vertices.len() {
+            return true;
+        }
+        // colors are tried in order and a new color is opened only once,
+        // so that permutations of the classes are not explored twice
+        let used = colors[..i].iter().max().map_or(0, |&c| c + 1);
+        for c in 0..self.k.min(used + 1) {
+            let conflict =
+                (0..i).any(|j| colors[j] == c && graph.is_adjacent(vertices[i], vertices[j]));
+            if !conflict {
+                colors[i] = c;
+                if self.assign(graph, vertices, colors, i + 1) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+impl Property for KColorable {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        self.coloring(graph, &set.to_set()).is_some()
+    }
+}
+
+/// maximal vertex sets inducing a k-colorable subgraph.
+///
+/// adding v and dropping N(v) from one color class of a fixed coloring of S misses the
+/// solutions that recolor S, so the removable sets are the minimal X such that
+/// S ∪ {v} \ X has some k-coloring, as for every hereditary property. the obstructions
+/// are the k-critical subgraphs through v, which may be large, and each feasibility check
+/// colors by backtracking, so `neighbors` is exponential in the worst case.
+pub type MaximalInducedKColorableSubgraph = HereditaryProblem<KColorable>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::CanonicalReconstruction;
+
+    #[test]
+    fn for_maximal_induced_k_colorable_subgraph() {
+        for k in 2..=4 {
+            for edges in random_graphs(9) {
+                let problem = MaximalInducedKColorableSubgraph::init(9, edges, KColorable::new(k));
+                assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+            }
+        }
+    }
+
+    #[test]
+    fn for_recoloring() {
+        // S = {0, 1, 2, 5, 7} and S* = {1, 5, 6, 7} for k = 2: 1 and 5 are on opposite sides
+        // of S but on the same side of S*, so adding 6 must recolor S to keep S ∩ S*
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 4),
+            (0, 6),
+            (1, 3),
+            (1, 6),
+            (2, 4),
+            (2, 5),
+            (2, 6),
+            (2, 7),
+            (3, 4),
+            (3, 5),
+            (3, 6),
+            (3, 7),
+            (4, 5),
+            (4, 6),
+            (4, 7),
+            (5, 6),
+        ]
+        .into_iter()
+        .collect::<HashSet<_>>();
+        let problem = MaximalInducedKColorableSubgraph::init(8, edges, KColorable::new(2));
+        let kept = [1, 5, 6, 7].into_iter().collect::<HashSet<_>>();
+        assert!(problem
+            .neightbors(&VertexSet(vec![0, 1, 2, 5, 7]), 6)
+            .iter()
+            .any(|s| kept.is_subset(&s.to_set())));
+    }
+}
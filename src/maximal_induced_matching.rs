@@ -0,0 +1,117 @@
+#![allow(dead_code)]
+use crate::graph::{intersection, EdgeSet, Graph};
+use crate::{CanonicalReconstruction, ProsimitySearchable};
+use std::collections::HashSet;
+
+/// maximal induced matchings of G, i.e., edge sets M such that no two edges of M
+/// share an endpoint or are joined by an edge of G.
+///
+/// the universe is E(G) rather than V(G), and an edge is identified by its index in it.
+pub struct MaximalInducedMatchings {
+    graph: Graph,
+    universe: Vec<(usize, usize)>,
+}
+
+impl MaximalInducedMatchings {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>) -> Self {
+        let graph = Graph::new(vertices, edges);
+        let universe = graph.edge_list();
+        Self { graph, universe }
+    }
+
+    /// greedily add edges in increasing order while the set stays an induced matching
+    fn comp(&self, mut component: HashSet<usize>) -> HashSet<usize> {
+        for n in 0..self.universe.len() {
+            if !component.contains(&n) && component.iter().all(|&e| !self.conflict(e, n)) {
+                component.insert(n);
+            }
+        }
+        component
+    }
+
+    /// whether the edges e and f cannot both be in an induced matching
+    fn conflict(&self, e: usize, f: usize) -> bool {
+        let (u, v) = self.universe[e];
+        let (x, y) = self.universe[f];
+        e != f
+            && [(u, x), (u, y), (v, x), (v, y)]
+                .iter()
+                .any(|&(a, b)| a == b || self.graph.is_adjacent(a, b))
+    }
+
+    fn is_induced_matching(&self, set: &HashSet<usize>) -> bool {
+        set.iter()
+            .all(|&e| set.iter().all(|&f| !self.conflict(e, f)))
+    }
+}
+
+impl ProsimitySearchable for MaximalInducedMatchings {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = EdgeSet;
+
+    fn proximity(solution: &EdgeSet, solution_: &EdgeSet) -> HashSet<usize> {
+        intersection(&solution.to_set(), &solution_.to_set())
+    }
+
+    fn neighbors(&self, solution: &EdgeSet) -> Vec<EdgeSet> {
+        (0..self.universe.len())
+            .filter(|e| !solution.indices.contains(e))
+            .flat_map(|e| self.neightbors(solution, e))
+            .collect()
+    }
+
+    fn start(&self) -> EdgeSet {
+        EdgeSet::new(&self.comp(HashSet::new()), &self.universe)
+    }
+}
+
+impl CanonicalReconstruction for MaximalInducedMatchings {
+    type Universe = Vec<(usize, usize)>;
+    type Components = HashSet<usize>;
+    type Solutions = EdgeSet;
+
+    /// the only removable set is the edges of S in conflict with e
+    fn neightbors(&self, solution: &EdgeSet, edge: usize) -> Vec<EdgeSet> {
+        let mut component = solution
+            .indices
+            .iter()
+            .copied()
+            .filter(|&f| !self.conflict(edge, f))
+            .collect::<HashSet<_>>();
+        component.insert(edge);
+        vec![EdgeSet::new(&self.comp(component), &self.universe)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_matchings() {
+        for edges in random_graphs(6) {
+            let problem = MaximalInducedMatchings::init(6, edges);
+            assert_enumerates(&problem, problem.universe.len(), |set| {
+                problem.is_induced_matching(set)
+            });
+        }
+    }
+
+    #[test]
+    fn for_path() {
+        // 0 - 1 - 2 - 3 - 4
+        let edges = vec![(0, 1), (2, 1), (2, 3), (3, 4)].into_iter().collect();
+        let problem = MaximalInducedMatchings::init(5, edges);
+        let solutions = problem
+            .enemurate()
+            .into_iter()
+            .map(|s| s.edges)
+            .collect::<HashSet<_>>();
+        let expected = [vec![(0, 1), (3, 4)], vec![(1, 2)], vec![(2, 3)]]
+            .into_iter()
+            .collect();
+        assert_eq!(solutions, expected);
+    }
+}
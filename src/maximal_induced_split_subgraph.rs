@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+use crate::graph::{print_vec, set_to_vec, Graph, VertexSet};
+use crate::hereditary::{Certificate, HereditaryProblem, Property};
+use std::collections::HashSet;
+
+/// a vertex set inducing a split graph, with its partition into a clique and an
+/// independent set. the partition is a function of the vertex set, see `partition`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SplitPartition {
+    pub clique: Vec<usize>,
+    pub independent: Vec<usize>,
+}
+
+impl SplitPartition {
+    pub fn to_set(&self) -> HashSet<usize> {
+        self.clique
+            .iter()
+            .chain(&self.independent)
+            .copied()
+            .collect()
+    }
+}
+
+impl std::fmt::Display for SplitPartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "K: {:?}, I: {:?}",
+            print_vec(&self.clique),
+            print_vec(&self.independent)
+        )
+    }
+}
+
+impl Certificate<Split> for SplitPartition {
+    fn certify(graph: &Graph, _: &Split, set: &HashSet<usize>) -> Self {
+        Split::partition(graph, set).unwrap()
+    }
+
+    fn to_set(&self) -> HashSet<usize> {
+        SplitPartition::to_set(self)
+    }
+}
+
+/// the property of inducing a split graph. the minimal non-split graphs are 2K2, C4 and
+/// C5, so the obstructions found by shrinking have at most five vertices.
+pub struct Split;
+
+impl Split {
+    /// clique / independent partition of G[set], or None if G[set] is not split.
+    ///
+    /// by Hammer and Simeone, with degrees d_1 ≥ ... ≥ d_n in G[set] and
+    /// m = max{i : d_i ≥ i - 1}, G[set] is split iff
+    /// d_1 + ... + d_m = m(m - 1) + d_{m+1} + ... + d_n,
+    /// and then the first m vertices form a clique. ties are broken by vertex number.
+    pub fn partition(graph: &Graph, set: &HashSet<usize>) -> Option<SplitPartition> {
+        let mut vertices = set_to_vec(set)
+            .into_iter()
+            .map(|v| {
+                let degree = set.iter().filter(|&&u| graph.is_adjacent(u, v)).count();
+                (degree, v)
+            })
+            .collect::<Vec<_>>();
+        vertices.sort_by_key(|&(degree, v)| (std::cmp::Reverse(degree), v));
+        let m = vertices
+            .iter()
+            .enumerate()
+            .filter(|&(i, &(degree, _))| degree >= i)
+            .map(|(i, _)| i + 1)
+            .max()
+            .unwrap_or(0);
+        let head = vertices[..m].iter().map(|e| e.0).sum::<usize>();
+        let tail = vertices[m..].iter().map(|e| e.0).sum::<usize>();
+        if head != m * m.saturating_sub(1) + tail {
+            return None;
+        }
+        let mut clique = vertices[..m].iter().map(|e| e.1).collect::<Vec<_>>();
+        let mut independent = vertices[m..].iter().map(|e| e.1).collect::<Vec<_>>();
+        clique.sort();
+        independent.sort();
+        Some(SplitPartition {
+            clique,
+            independent,
+        })
+    }
+}
+
+impl Property for Split {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        Self::partition(graph, &set.to_set()).is_some()
+    }
+}
+
+/// maximal vertex sets inducing a split graph, each with its partition.
+pub type MaximalInducedSplitSubgraph = HereditaryProblem<Split, SplitPartition>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::ProsimitySearchable;
+
+    #[test]
+    fn for_maximal_induced_split_subgraph() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedSplitSubgraph::with_certificates(9, edges, Split);
+            let solutions = problem.enemurate();
+            for s in &solutions {
+                let clique = s.clique.iter().copied().collect::<HashSet<_>>();
+                let independent = s.independent.iter().copied().collect::<HashSet<_>>();
+                assert!(clique.iter().all(|&u| clique
+                    .iter()
+                    .all(|&v| u == v || problem.graph.is_adjacent(u, v))));
+                assert!(independent.iter().all(|&u| independent
+                    .iter()
+                    .all(|&v| !problem.graph.is_adjacent(u, v))));
+            }
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+use crate::graph::{Graph, VertexSet};
+use crate::hereditary::{HereditaryProblem, Property};
+
+/// the property of inducing a triangle-free subgraph.
+pub struct TriangleFree;
+
+impl TriangleFree {
+    /// an edge (a, b) of G[set] forming a triangle with v, if any
+    fn triangle(graph: &Graph, set: &[usize], v: usize) -> Option<(usize, usize)> {
+        let n_v = set
+            .iter()
+            .copied()
+            .filter(|&u| graph.is_adjacent(u, v))
+            .collect::<Vec<_>>();
+        for (i, &a) in n_v.iter().enumerate() {
+            for &b in &n_v[i + 1..] {
+                if graph.is_adjacent(a, b) {
+                    return Some((a, b));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Property for TriangleFree {
+    fn is_feasible(&self, graph: &Graph, set: &VertexSet) -> bool {
+        // every triangle is found when its largest vertex is added
+        (0..set.0.len()).all(|i| Self::triangle(graph, &set.0[..i], set.0[i]).is_none())
+    }
+
+    fn can_add(&self, graph: &Graph, set: &VertexSet, v: usize) -> bool {
+        Self::triangle(graph, &set.0, v).is_none()
+    }
+
+    /// an uncovered edge of G[N(v) ∩ S], so that the removable sets are the minimal
+    /// vertex covers of G[N(v) ∩ S]
+    fn obstruction(&self, graph: &Graph, set: &VertexSet, v: usize) -> Option<Vec<usize>> {
+        Self::triangle(graph, &set.0, v).map(|(a, b)| vec![a, b])
+    }
+}
+
+/// maximal vertex sets inducing a triangle-free subgraph.
+pub type MaximalInducedTriangleFreeSubgraph = HereditaryProblem<TriangleFree>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+
+    #[test]
+    fn for_maximal_induced_triangle_free_subgraph() {
+        for edges in random_graphs(9) {
+            let problem = MaximalInducedTriangleFreeSubgraph::init(9, edges, TriangleFree);
+            assert_enumerates(&problem, 9, |set| problem.is_feasible(set));
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+use crate::graph::VertexSet;
+use crate::maximal_induced_bounded_degree_subgraph::{BoundedDegree, MaximalInducedBoundedDegree};
+use crate::ProsimitySearchable;
+use std::collections::HashSet;
+
+/// maximal k-plexes of G, i.e., vertex sets S in which every vertex is adjacent to all but
+/// at most k - 1 other vertices of S.
+///
+/// S is a k-plex of G iff the complement of G[S] has maximum degree at most k - 1,
+/// so this is [`MaximalInducedBoundedDegree`] on the complement of G.
+pub struct MaximalKPlexes {
+    complement: MaximalInducedBoundedDegree,
+}
+
+impl MaximalKPlexes {
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>, k: usize) -> Self {
+        assert!(k > 0, "k must be positive");
+        let complement = (0..vertices)
+            .flat_map(|u| (u + 1..vertices).map(move |v| (u, v)))
+            .filter(|&(u, v)| !edges.contains(&(u, v)) && !edges.contains(&(v, u)))
+            .collect();
+        Self {
+            complement: MaximalInducedBoundedDegree::init(
+                vertices,
+                complement,
+                BoundedDegree::new(k - 1),
+            ),
+        }
+    }
+
+    /// maximal k-plexes of at least `min_size` vertices. smaller k-plexes cannot be
+    /// skipped during the search, as they connect the solution graph.
+    pub fn large_plexes(&self, min_size: usize) -> HashSet<VertexSet> {
+        self.enemurate()
+            .into_iter()
+            .filter(|s| s.0.len() >= min_size)
+            .collect()
+    }
+}
+
+impl ProsimitySearchable for MaximalKPlexes {
+    type Universe = Vec<usize>;
+    type Components = HashSet<usize>;
+    type Solutions = VertexSet;
+
+    fn proximity(solution: &VertexSet, solution_: &VertexSet) -> HashSet<usize> {
+        MaximalInducedBoundedDegree::proximity(solution, solution_)
+    }
+
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        self.complement.neighbors(solution)
+    }
+
+    fn start(&self) -> VertexSet {
+        self.complement.start()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{assert_enumerates, random_graphs};
+    use crate::graph::Graph;
+
+    #[test]
+    fn for_maximal_k_plexes() {
+        for k in 1..=3 {
+            for edges in random_graphs(9) {
+                let graph = Graph::new(9, edges.clone());
+                let problem = MaximalKPlexes::init(9, edges, k);
+                let is_k_plex = |set: &HashSet<usize>| {
+                    set.iter().all(|&u| {
+                        set.iter().filter(|&&v| graph.is_adjacent(u, v)).count() + k >= set.len()
+                    })
+                };
+                assert_enumerates(&problem, 9, is_k_plex);
+            }
+        }
+    }
+
+    #[test]
+    fn for_min_size() {
+        // a 4-cycle with a pendant vertex 4
+        let edges = vec![(0, 1), (1, 2), (2, 3), (3, 0), (3, 4)]
+            .into_iter()
+            .collect();
+        let problem = MaximalKPlexes::init(5, edges, 2);
+        let expected = [VertexSet(vec![0, 1, 2, 3])].into_iter().collect();
+        assert_eq!(problem.large_plexes(4), expected);
+    }
+}
@@ -0,0 +1,232 @@
+#![allow(dead_code)]
+use crate::graph::{set_to_vec, Graph, VertexSet};
+use std::collections::{HashSet, VecDeque};
+
+/// maximal s-clubs of G, i.e., vertex sets whose induced subgraph has diameter at most s.
+///
+/// s-clubs are not hereditary: a subset of an s-club, even a connected one, may have a
+/// larger diameter. the traversal below works on the vertex sets contained in some s-club,
+/// which are hereditary, but `comp` and the removable sets search over subsets. even
+/// deciding whether an s-club is maximal is NP-complete for s ≥ 2 (Mahdavi Pajouh and
+/// Balasundaram), so `comp` cannot run in polynomial time unless P = NP, and this is an
+/// exponential reference enumerator rather than a `ProsimitySearchable`.
+pub struct MaximalSClubs {
+    graph: Graph,
+    s: usize,
+}
+
+impl MaximalSClubs {
+    /// s defaults to 2
+    pub fn init(vertices: usize, edges: HashSet<(usize, usize)>, s: Option<usize>) -> Self {
+        Self {
+            graph: Graph::new(vertices, edges),
+            s: s.unwrap_or(2),
+        }
+    }
+
+    /// all maximal s-clubs, by a traversal of the solutions from `comp(∅)`
+    pub fn enemurate(&self) -> HashSet<VertexSet> {
+        let start = VertexSet::from(self.comp(HashSet::new()));
+        let mut solutions = HashSet::new();
+        solutions.insert(start.clone());
+        let mut stack = vec![start];
+        while let Some(solution) = stack.pop() {
+            for s in self.neighbors(&solution) {
+                if solutions.insert(s.clone()) {
+                    stack.push(s);
+                }
+            }
+        }
+        solutions
+    }
+
+    /// COMP(S ∪ {v} \ X) for each v ∉ S and each removable set X
+    fn neighbors(&self, solution: &VertexSet) -> Vec<VertexSet> {
+        let set = &solution.to_set();
+        (0..self.graph.vertices)
+            .filter(|v| !set.contains(v))
+            .flat_map(|v| {
+                self.removable_sets(set, v).into_iter().map(move |x| {
+                    let mut component = set.difference(&x).copied().collect::<HashSet<_>>();
+                    component.insert(v);
+                    component
+                })
+            })
+            .map(|component| self.comp(component).into())
+            .collect()
+    }
+
+    /// whether every two vertices of `set` are within distance s in G[set],
+    /// by BFS from each vertex inside G[set]
+    fn is_s_club(&self, set: &HashSet<usize>) -> bool {
+        set.iter().all(|&v| {
+            let mut distance = vec![None; self.graph.vertices];
+            distance[v] = Some(0);
+            let mut queue = VecDeque::new();
+            queue.push_back(v);
+            let mut reached = 1;
+            while let Some(u) = queue.pop_front() {
+                let d = distance[u].unwrap();
+                if d == self.s {
+                    continue;
+                }
+                for &w in set {
+                    if distance[w].is_none() && self.graph.is_adjacent(u, w) {
+                        distance[w] = Some(d + 1);
+                        reached += 1;
+                        queue.push_back(w);
+                    }
+                }
+            }
+            reached == set.len()
+        })
+    }
+
+    /// vertices within distance s of v in G. every s-club containing v lies in this ball,
+    /// since distances in an induced subgraph are at least those in G.
+    fn ball(&self, v: usize) -> HashSet<usize> {
+        let mut distance = vec![None; self.graph.vertices];
+        distance[v] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(v);
+        while let Some(u) = queue.pop_front() {
+            let d = distance[u].unwrap();
+            if d == self.s {
+                continue;
+            }
+            for w in self.graph.neighborhood(u) {
+                if distance[w].is_none() {
+                    distance[w] = Some(d + 1);
+                    queue.push_back(w);
+                }
+            }
+        }
+        (0..self.graph.vertices)
+            .filter(|&w| distance[w].is_some())
+            .collect()
+    }
+
+    /// extend `component`, which is contained in some s-club, to a maximal s-club.
+    ///
+    /// single vertices do not suffice: an s-club may only be reached by adding several
+    /// vertices at once. so the subsets of the candidates, i.e., of the vertices within
+    /// distance s of every vertex of the set, are tried by increasing size.
+    fn comp(&self, component: HashSet<usize>) -> HashSet<usize> {
+        let mut component = self.club_containing(&component).unwrap();
+        loop {
+            match self.extension(&component, &self.candidates(&component)) {
+                Some(extension) => component.extend(extension),
+                None => return component,
+            }
+        }
+    }
+
+    /// a smallest s-club containing `set`, if any
+    fn club_containing(&self, set: &HashSet<usize>) -> Option<HashSet<usize>> {
+        if self.is_s_club(set) {
+            return Some(set.clone());
+        }
+        let extension = self.extension(set, &self.candidates(set))?;
+        let mut club = set.clone();
+        club.extend(extension);
+        Some(club)
+    }
+
+    fn candidates(&self, set: &HashSet<usize>) -> Vec<usize> {
+        let balls = set.iter().map(|&u| self.ball(u)).collect::<Vec<_>>();
+        (0..self.graph.vertices)
+            .filter(|n| !set.contains(n) && balls.iter().all(|ball| ball.contains(n)))
+            .collect()
+    }
+
+    /// a smallest nonempty subset of `candidates` extending `set` to an s-club, if any
+    fn extension(&self, set: &HashSet<usize>, candidates: &[usize]) -> Option<Vec<usize>> {
+        (1..=candidates.len()).find_map(|size| {
+            subsets(candidates, size).find(|subset| {
+                let mut extended = set.clone();
+                extended.extend(subset);
+                self.is_s_club(&extended)
+            })
+        })
+    }
+
+    /// all minimal X ⊆ S such that S ∪ {v} \ X is contained in some s-club,
+    /// by increasing size. X always contains S minus the ball around v.
+    fn removable_sets(&self, solution: &HashSet<usize>, v: usize) -> Vec<HashSet<usize>> {
+        let ball = self.ball(v);
+        let far = solution.difference(&ball).copied().collect::<HashSet<_>>();
+        let near = set_to_vec(&solution.intersection(&ball).copied().collect());
+        let mut found: Vec<HashSet<usize>> = Vec::new();
+        for size in 0..=near.len() {
+            for subset in subsets(&near, size) {
+                let mut x = far.clone();
+                x.extend(subset);
+                if found.iter().any(|f| f.is_subset(&x)) {
+                    continue;
+                }
+                let mut component = solution.difference(&x).copied().collect::<HashSet<_>>();
+                component.insert(v);
+                if self.club_containing(&component).is_some() {
+                    found.push(x);
+                }
+            }
+        }
+        found
+    }
+}
+
+/// subsets of `items` of the given size, in lexicographic order
+fn subsets(items: &[usize], size: usize) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let mut indices = (0..size).collect::<Vec<_>>();
+    let mut done = size > items.len();
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let subset = indices.iter().map(|&i| items[i]).collect();
+        // advance to the next combination
+        match (0..size)
+            .rev()
+            .find(|&i| indices[i] != i + items.len() - size)
+        {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..size {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => done = true,
+        }
+        Some(subset)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::brute_force::{maximal_sets, random_graphs};
+
+    #[test]
+    fn for_maximal_s_clubs() {
+        for s in 1..=3 {
+            for edges in random_graphs(9) {
+                let problem = MaximalSClubs::init(9, edges, Some(s));
+                let solutions = problem
+                    .enemurate()
+                    .into_iter()
+                    .map(|s| s.0)
+                    .collect::<HashSet<_>>();
+                assert_eq!(solutions, maximal_sets(9, |set| problem.is_s_club(set)));
+            }
+        }
+    }
+
+    #[test]
+    fn for_cycle() {
+        // C5 is a 2-club, although removing any vertex leaves a P4 of diameter 3
+        let edges = (0..5).map(|i| (i, (i + 1) % 5)).collect();
+        let problem = MaximalSClubs::init(5, edges, None);
+        let expected = [VertexSet(vec![0, 1, 2, 3, 4])].into_iter().collect();
+        assert_eq!(problem.enemurate(), expected);
+    }
+}